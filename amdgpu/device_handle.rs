@@ -611,6 +611,27 @@ impl DeviceHandle {
 
         pci_bus.get_max_system_link()
     }
+
+    /// [PCI::BUS_INFO::get_aer_counters]
+    pub fn get_aer_counters(&self, type_: PCI::AerType) -> Option<PCI::AerCounters> {
+        let pci_bus = self.get_pci_bus_info().ok()?;
+
+        pci_bus.get_aer_counters(type_)
+    }
+
+    /// [PCI::BUS_INFO::get_pcie_replay_count]
+    pub fn get_pcie_replay_count(&self) -> Option<u64> {
+        let pci_bus = self.get_pci_bus_info().ok()?;
+
+        pci_bus.get_pcie_replay_count()
+    }
+
+    /// [PCI::BUS_INFO::get_pcie_bw]
+    pub fn get_pcie_bw(&self) -> Option<PCI::PcieBw> {
+        let pci_bus = self.get_pci_bus_info().ok()?;
+
+        pci_bus.get_pcie_bw()
+    }
 }
 
 impl Drop for DeviceHandle {
@@ -0,0 +1,237 @@
+use crate::AMDGPU::DeviceHandle;
+use std::fmt;
+use std::path::PathBuf;
+use super::parse_hwmon;
+
+/* ref: https://www.kernel.org/doc/html/latest/hwmon/sysfs-interface.html */
+/* ref: https://www.kernel.org/doc/html/latest/gpu/amdgpu/thermal.html#hwmon-interfaces */
+
+impl DeviceHandle {
+    /// Get all `temp*`, `in*`, `power*`, `energy*` and `freq*` sensors from the hwmon directory
+    pub fn get_all_hwmon_sensors(&self) -> Vec<HwmonSensor> {
+        let Some(hwmon_path) = self.get_hwmon_path() else { return Vec::new() };
+
+        HwmonSensor::get_all_from_hwmon_path(hwmon_path)
+    }
+
+    pub fn get_hwmon_sensor(&self, type_: HwmonSensorType, index: u32) -> Option<HwmonSensor> {
+        let hwmon_path = self.get_hwmon_path()?;
+
+        HwmonSensor::from_hwmon_path(hwmon_path, type_, index)
+    }
+}
+
+/// Values are in the units of the hwmon sysfs interface, see [HwmonSensorUnit]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HwmonSensor {
+    pub type_: HwmonSensorType,
+    pub index: u32,
+    /// e.g. "edge", "junction", "mem", "vddgfx", "vddnb", "PPT", "sclk", "mclk"
+    pub label: Option<String>,
+    pub input: Option<i64>,
+    /// `power*_average`
+    pub average: Option<i64>,
+    pub min: Option<i64>,
+    pub max: Option<i64>,
+    pub crit: Option<i64>,
+    pub crit_hyst: Option<i64>,
+    pub emergency: Option<i64>,
+    /// `power*_cap`
+    pub cap: Option<i64>,
+}
+
+impl HwmonSensor {
+    pub fn get_all_from_hwmon_path<P: Into<PathBuf>>(path: P) -> Vec<Self> {
+        let path = path.into();
+        let Ok(dir) = std::fs::read_dir(&path) else { return Vec::new() };
+
+        let mut ids: Vec<(HwmonSensorType, u32)> = dir.filter_map(|entry| {
+            let name = entry.ok()?.file_name();
+
+            HwmonSensorType::parse_file_name(name.to_str()?)
+        }).collect();
+
+        ids.sort();
+        ids.dedup();
+
+        ids.into_iter().filter_map(|(type_, index)| {
+            Self::from_hwmon_path(&path, type_, index)
+        }).collect()
+    }
+
+    pub fn from_hwmon_path<P: Into<PathBuf>>(
+        path: P,
+        type_: HwmonSensorType,
+        index: u32,
+    ) -> Option<Self> {
+        let path = path.into();
+        let pre = format!("{}{index}", type_.prefix());
+
+        let label = std::fs::read_to_string(path.join(format!("{pre}_label")))
+            .ok()
+            .map(|s| s.trim_end().to_string());
+        let [input, average, min, max, crit, crit_hyst, emergency, cap] = [
+            "input",
+            "average",
+            "min",
+            "max",
+            "crit",
+            "crit_hyst",
+            "emergency",
+            "cap",
+        ].map(|suffix| parse_hwmon::<i64, _>(path.join(format!("{pre}_{suffix}"))));
+
+        if label.is_none() && input.is_none() && average.is_none() {
+            return None;
+        }
+
+        Some(Self {
+            type_,
+            index,
+            label,
+            input,
+            average,
+            min,
+            max,
+            crit,
+            crit_hyst,
+            emergency,
+            cap,
+        })
+    }
+
+    /// Re-read `*_input` and `*_average`
+    pub fn update<P: Into<PathBuf>>(&mut self, path: P) {
+        let path = path.into();
+        let pre = format!("{}{}", self.type_.prefix(), self.index);
+
+        if let Some(v) = parse_hwmon::<i64, _>(path.join(format!("{pre}_input"))) {
+            self.input = Some(v);
+        }
+
+        if let Some(v) = parse_hwmon::<i64, _>(path.join(format!("{pre}_average"))) {
+            self.average = Some(v);
+        }
+    }
+
+    pub fn unit(&self) -> HwmonSensorUnit {
+        self.type_.unit()
+    }
+
+    /// `input`, or `average` if `input` is not available
+    pub fn value(&self) -> Option<i64> {
+        self.input.or(self.average)
+    }
+
+    /// Convert the value to °C, V, W, J or Hz
+    pub fn value_in_base_unit(&self) -> Option<f64> {
+        let v = self.value()?;
+
+        Some(self.unit().to_base_unit(v))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum HwmonSensorType {
+    Temp,
+    /// Voltage
+    In,
+    Power,
+    Energy,
+    Freq,
+}
+
+impl HwmonSensorType {
+    const ALL: [Self; 5] = [Self::Temp, Self::In, Self::Power, Self::Energy, Self::Freq];
+
+    pub const fn prefix(&self) -> &str {
+        match self {
+            Self::Temp => "temp",
+            Self::In => "in",
+            Self::Power => "power",
+            Self::Energy => "energy",
+            Self::Freq => "freq",
+        }
+    }
+
+    pub const fn unit(&self) -> HwmonSensorUnit {
+        match self {
+            Self::Temp => HwmonSensorUnit::MilliDegreeCelsius,
+            Self::In => HwmonSensorUnit::MilliVolt,
+            Self::Power => HwmonSensorUnit::MicroWatt,
+            Self::Energy => HwmonSensorUnit::MicroJoule,
+            Self::Freq => HwmonSensorUnit::Hertz,
+        }
+    }
+
+    /// "temp2_label" -> `(Temp, 2)`
+    fn parse_file_name(name: &str) -> Option<(Self, u32)> {
+        let (pre, suffix) = name.split_once('_')?;
+
+        if !matches!(suffix, "input" | "label" | "average") {
+            return None;
+        }
+
+        Self::ALL.into_iter().find_map(|type_| {
+            let index = pre.strip_prefix(type_.prefix())?.parse::<u32>().ok()?;
+
+            Some((type_, index))
+        })
+    }
+}
+
+impl fmt::Display for HwmonSensorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[test]
+fn test_hwmon_sensor_parse_file_name() {
+    assert_eq!(HwmonSensorType::parse_file_name("temp2_label"), Some((HwmonSensorType::Temp, 2)));
+    assert_eq!(HwmonSensorType::parse_file_name("in0_input"), Some((HwmonSensorType::In, 0)));
+    assert_eq!(HwmonSensorType::parse_file_name("power1_average"), Some((HwmonSensorType::Power, 1)));
+    assert_eq!(HwmonSensorType::parse_file_name("freq1_input"), Some((HwmonSensorType::Freq, 1)));
+    assert_eq!(HwmonSensorType::parse_file_name("temp1_crit"), None);
+    assert_eq!(HwmonSensorType::parse_file_name("pwm1_enable"), None);
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HwmonSensorUnit {
+    MilliDegreeCelsius,
+    MilliVolt,
+    MicroWatt,
+    MicroJoule,
+    Hertz,
+}
+
+impl HwmonSensorUnit {
+    const fn divisor(&self) -> u32 {
+        match self {
+            Self::MilliDegreeCelsius |
+            Self::MilliVolt => 1_000,
+            Self::MicroWatt |
+            Self::MicroJoule => 1_000_000,
+            Self::Hertz => 1,
+        }
+    }
+
+    /// Convert to °C, V, W, J or Hz
+    pub fn to_base_unit(&self, v: i64) -> f64 {
+        v as f64 / self.divisor() as f64
+    }
+}
+
+impl fmt::Display for HwmonSensorUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Self::MilliDegreeCelsius => "m°C",
+            Self::MilliVolt => "mV",
+            Self::MicroWatt => "µW",
+            Self::MicroJoule => "µJ",
+            Self::Hertz => "Hz",
+        };
+
+        write!(f, "{s}")
+    }
+}
@@ -0,0 +1,422 @@
+use core::mem::size_of;
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use super::read_struct;
+use crate::AMDGPU::{DeviceHandle, HwId, IpDieEntry, IpHwId, IpHwInstance};
+use crate::bindings::discovery::*;
+
+/* ref: drivers/gpu/drm/amd/include/discovery.h */
+/* ref: drivers/gpu/drm/amd/amdgpu/amdgpu_discovery.c */
+
+const DEBUGFS_NAME: &str = "amdgpu_discovery";
+
+impl DeviceHandle {
+    /// Parse `amdgpu_discovery` (debugfs), requires root privileges.
+    pub fn get_ip_discovery_binary(&self) -> io::Result<IpDiscoveryBinary> {
+        let pci_bus = self.get_pci_bus_info().map_err(|_| io::Error::other("Failed get_pci_bus_info"))?;
+
+        IpDiscoveryBinary::from_file(pci_bus.get_debug_dri_path()?.join(DEBUGFS_NAME))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum IpDiscoveryParseError {
+    InvalidBinarySignature,
+    InvalidDiscoveryTableSignature,
+    OutOfRange,
+}
+
+/// GPU IP discovery binary, as `/sys/kernel/debug/dri/#/amdgpu_discovery` or `amdgpu/*_ip_discovery.bin`
+#[derive(Debug, Clone)]
+pub struct IpDiscoveryBinary {
+    pub version_major: u16,
+    pub version_minor: u16,
+    pub binary_checksum_valid: bool,
+    /// version of `ip_discovery_header`
+    pub ip_discovery_version: u16,
+    /// The same entries as `ip_discovery/die/` in sysfs
+    pub dies: Vec<IpDieEntry>,
+    pub gc_info: Option<GcInfo>,
+    /// Harvested IP instances
+    pub harvest_info: Vec<IpHarvestInfo>,
+    pub vcn_info: Vec<VcnInstanceInfo>,
+    pub mall_info: Option<MallInfo>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IpHarvestInfo {
+    pub hw_id: HwId,
+    pub number_instance: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VcnInstanceInfo {
+    pub instance_num: u32,
+    pub av1_disabled: bool,
+    pub vp9_disabled: bool,
+    pub hevc_disabled: bool,
+    pub h264_disabled: bool,
+}
+
+#[derive(Debug, Clone)]
+pub enum GcInfo {
+    V1_0(gc_info_v1_0),
+    V1_1(gc_info_v1_1),
+    V1_2(gc_info_v1_2),
+    V1_3(gc_info_v1_3),
+    V2_0(gc_info_v2_0),
+    V2_1(gc_info_v2_1),
+    Unknown(gpu_info_header),
+}
+
+/* `gc_info_v1_1` - `gc_info_v1_3` (`gc_info_v2_1`) start with the fields of `gc_info_v1_0` (`gc_info_v2_0`) */
+fn prefix<T, P>(t: &T) -> Option<P> {
+    let bytes = unsafe { core::slice::from_raw_parts(t as *const T as *const u8, size_of::<T>()) };
+
+    read_struct(bytes, 0)
+}
+
+impl GcInfo {
+    fn v1_0(&self) -> Option<gc_info_v1_0> {
+        match self {
+            Self::V1_0(v) => Some(*v),
+            Self::V1_1(v) => prefix(v),
+            Self::V1_2(v) => prefix(v),
+            Self::V1_3(v) => prefix(v),
+            _ => None,
+        }
+    }
+
+    fn v2_0(&self) -> Option<gc_info_v2_0> {
+        match self {
+            Self::V2_0(v) => Some(*v),
+            Self::V2_1(v) => prefix(v),
+            _ => None,
+        }
+    }
+
+    pub fn header(&self) -> gpu_info_header {
+        match self {
+            Self::Unknown(h) => *h,
+            _ => self.v1_0().map(|v| v.header).or(self.v2_0().map(|v| v.header)).unwrap(),
+        }
+    }
+
+    pub fn num_se(&self) -> Option<u32> {
+        self.v1_0().map(|v| v.gc_num_se).or(self.v2_0().map(|v| v.gc_num_se))
+    }
+
+    /// SA (v1), SH (v2)
+    pub fn num_sa_per_se(&self) -> Option<u32> {
+        self.v1_0().map(|v| v.gc_num_sa_per_se).or(self.v2_0().map(|v| v.gc_num_sh_per_se))
+    }
+
+    /// `(gc_num_wgp0_per_sa + gc_num_wgp1_per_sa) * 2` (v1), `gc_num_cu_per_sh` (v2).
+    /// `None` on overflow.
+    pub fn num_cu_per_sa(&self) -> Option<u32> {
+        match self.v1_0() {
+            Some(v) => v.gc_num_wgp0_per_sa.checked_add(v.gc_num_wgp1_per_sa)?.checked_mul(2),
+            None => self.v2_0().map(|v| v.gc_num_cu_per_sh),
+        }
+    }
+
+    pub fn num_rb_per_se(&self) -> Option<u32> {
+        self.v1_0().map(|v| v.gc_num_rb_per_se).or(self.v2_0().map(|v| v.gc_num_rb_per_se))
+    }
+
+    /// GL2C (v1), TCC (v2)
+    pub fn num_tcc(&self) -> Option<u32> {
+        self.v1_0().map(|v| v.gc_num_gl2c).or(self.v2_0().map(|v| v.gc_num_tccs))
+    }
+
+    pub fn wave_size(&self) -> Option<u32> {
+        self.v1_0().map(|v| v.gc_wave_size).or(self.v2_0().map(|v| v.gc_wave_size))
+    }
+
+    pub fn lds_size(&self) -> Option<u32> {
+        self.v1_0().map(|v| v.gc_lds_size).or(self.v2_0().map(|v| v.gc_lds_size))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum MallInfo {
+    V1_0(mall_info_v1_0),
+    V2_0(mall_info_v2_0),
+    Unknown(gpu_info_header),
+}
+
+impl IpDiscoveryBinary {
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let bin = std::fs::read(path)?;
+
+        Self::parse(&bin).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("{e:?}")))
+    }
+
+    /// The binary may be preceded by the PSP header (256 bytes)
+    pub fn parse(bin: &[u8]) -> Result<Self, IpDiscoveryParseError> {
+        let bin = [0, PSP_HEADER_SIZE as usize]
+            .into_iter()
+            .filter_map(|offset| bin.get(offset..))
+            .find(|b| read_struct::<u32>(b, 0) == Some(BINARY_SIGNATURE))
+            .ok_or(IpDiscoveryParseError::InvalidBinarySignature)?;
+        let bhdr = read_struct::<binary_header>(bin, 0).ok_or(IpDiscoveryParseError::OutOfRange)?;
+        let table_list = bhdr.table_list;
+        let table_offset = |id: table| -> Option<usize> {
+            let offset = table_list.get(id as usize)?.offset as usize;
+
+            (offset != 0).then_some(offset)
+        };
+
+        let ihdr_offset = table_offset(table_IP_DISCOVERY).ok_or(IpDiscoveryParseError::OutOfRange)?;
+        let ihdr = read_struct::<ip_discovery_header>(bin, ihdr_offset).ok_or(IpDiscoveryParseError::OutOfRange)?;
+
+        if ihdr.signature != DISCOVERY_TABLE_SIGNATURE {
+            return Err(IpDiscoveryParseError::InvalidDiscoveryTableSignature);
+        }
+
+        let dies = Self::parse_dies(bin, &ihdr);
+        let gc_info = table_offset(table_GC).and_then(|offset| Self::parse_gc_info(bin, offset));
+        let harvest_info = table_offset(table_HARVEST_INFO)
+            .map(|offset| Self::parse_harvest_info(bin, offset))
+            .unwrap_or_default();
+        let vcn_info = table_offset(table_VCN_INFO)
+            .map(|offset| Self::parse_vcn_info(bin, offset))
+            .unwrap_or_default();
+        let mall_info = table_offset(table_MALL_INFO).and_then(|offset| Self::parse_mall_info(bin, offset));
+
+        Ok(Self {
+            version_major: bhdr.version_major,
+            version_minor: bhdr.version_minor,
+            binary_checksum_valid: Self::verify_binary_checksum(bin, &bhdr),
+            ip_discovery_version: ihdr.version,
+            dies,
+            gc_info,
+            harvest_info,
+            vcn_info,
+            mall_info,
+        })
+    }
+
+    /// ref: `amdgpu_discovery_verify_checksum`
+    fn verify_binary_checksum(bin: &[u8], bhdr: &binary_header) -> bool {
+        /* from the next of `binary_checksum` */
+        const OFFSET: usize = 10;
+        let Some(b) = bin.get(OFFSET..bhdr.binary_size as usize) else { return false };
+        let sum = b.iter().fold(0u16, |sum, v| sum.wrapping_add(*v as u16));
+
+        sum == bhdr.binary_checksum
+    }
+
+    fn parse_dies(bin: &[u8], ihdr: &ip_discovery_header) -> Vec<IpDieEntry> {
+        let die_info = ihdr.die_info;
+        let base_addr_64_bit = ihdr.version >= 4 && {
+            let flags = unsafe { ihdr.__bindgen_anon_1.__bindgen_anon_1 };
+
+            flags.base_addr_64_bit() != 0
+        };
+        let base_addr_size = if base_addr_64_bit { size_of::<u64>() } else { size_of::<u32>() };
+
+        die_info.iter().take(ihdr.num_dies as usize).filter_map(|die| {
+            let die_offset = die.die_offset as usize;
+            let dhdr = read_struct::<die_header>(bin, die_offset)?;
+            let mut ip_offset = die_offset + size_of::<die_header>();
+            let mut ips: BTreeMap<u16, Vec<IpHwInstance>> = BTreeMap::new();
+
+            for _ in 0..dhdr.num_ips {
+                let Some(ip) = read_struct::<ip_v4>(bin, ip_offset) else { break };
+                /* `harvest` (version 1, 2), `variant` (version 3, 4) as the kernel exposes in sysfs */
+                let harvest = if ihdr.version < 3 {
+                    read_struct::<self::ip>(bin, ip_offset).map_or(0, |v1| v1.harvest())
+                } else {
+                    ip.variant()
+                };
+                let base_offset = ip_offset + size_of::<ip_v4>();
+                /* the upper 32 bits are dropped as the kernel does */
+                let base_address = (0..ip.num_base_address as usize).filter_map(|i| {
+                    read_struct::<u32>(bin, base_offset + i * base_addr_size)
+                }).collect();
+
+                ips.entry(ip.hw_id).or_default().push(IpHwInstance {
+                    hw_id: HwId::from(ip.hw_id as isize),
+                    num_instance: ip.instance_number,
+                    major: ip.major,
+                    minor: ip.minor,
+                    revision: ip.revision,
+                    harvest,
+                    num_base_addresses: ip.num_base_address as isize,
+                    base_address,
+                });
+
+                ip_offset = base_offset + ip.num_base_address as usize * base_addr_size;
+            }
+
+            let ip_hw_ids = ips.into_iter().map(|(hw_id, mut instances)| {
+                instances.sort_by_key(|inst| inst.num_instance);
+
+                IpHwId { hw_id: HwId::from(hw_id as isize), instances }
+            }).collect();
+
+            Some(IpDieEntry { die_id: dhdr.die_id as usize, ip_hw_ids })
+        }).collect()
+    }
+
+    fn parse_gc_info(bin: &[u8], offset: usize) -> Option<GcInfo> {
+        let header = read_struct::<gpu_info_header>(bin, offset)?;
+
+        if header.table_id != GC_TABLE_ID { return None }
+
+        let gc_info = match (header.version_major, header.version_minor) {
+            (1, 0) => GcInfo::V1_0(read_struct(bin, offset)?),
+            (1, 1) => GcInfo::V1_1(read_struct(bin, offset)?),
+            (1, 2) => GcInfo::V1_2(read_struct(bin, offset)?),
+            (1, _) => GcInfo::V1_3(read_struct(bin, offset)?),
+            (2, 0) => GcInfo::V2_0(read_struct(bin, offset)?),
+            (2, _) => GcInfo::V2_1(read_struct(bin, offset)?),
+            _ => GcInfo::Unknown(header),
+        };
+
+        Some(gc_info)
+    }
+
+    fn parse_harvest_info(bin: &[u8], offset: usize) -> Vec<IpHarvestInfo> {
+        let Some(table) = read_struct::<harvest_table>(bin, offset) else { return Vec::new() };
+
+        if table.header.signature != HARVEST_TABLE_SIGNATURE { return Vec::new() }
+
+        let list = table.list;
+
+        list.iter()
+            .take_while(|info| info.hw_id != 0)
+            .map(|info| IpHarvestInfo {
+                hw_id: HwId::from(info.hw_id as isize),
+                number_instance: info.number_instance,
+            })
+            .collect()
+    }
+
+    fn parse_vcn_info(bin: &[u8], offset: usize) -> Vec<VcnInstanceInfo> {
+        let Some(vcn_info) = read_struct::<vcn_info_v1_0>(bin, offset) else { return Vec::new() };
+        let header = vcn_info.header;
+
+        if header.table_id != VCN_INFO_TABLE_ID || header.version_major != 1 { return Vec::new() }
+
+        let instance_info = vcn_info.instance_info;
+
+        instance_info.iter().take(vcn_info.num_of_instances as usize).map(|info| {
+            let fuse = unsafe { info.fuse_data.bits };
+
+            VcnInstanceInfo {
+                instance_num: info.instance_num,
+                av1_disabled: fuse.av1_disabled() != 0,
+                vp9_disabled: fuse.vp9_disabled() != 0,
+                hevc_disabled: fuse.hevc_disabled() != 0,
+                h264_disabled: fuse.h264_disabled() != 0,
+            }
+        }).collect()
+    }
+
+    fn parse_mall_info(bin: &[u8], offset: usize) -> Option<MallInfo> {
+        let header = read_struct::<gpu_info_header>(bin, offset)?;
+
+        if header.table_id != MALL_INFO_TABLE_ID { return None }
+
+        let mall_info = match header.version_major {
+            1 => MallInfo::V1_0(read_struct(bin, offset)?),
+            2 => MallInfo::V2_0(read_struct(bin, offset)?),
+            _ => MallInfo::Unknown(header),
+        };
+
+        Some(mall_info)
+    }
+
+    /// Same as [IpDieEntry::get_all_entries_from_sysfs], for kernels without `ip_discovery/` in sysfs
+    pub fn get_all_entries_from_debugfs<P: Into<PathBuf>>(debug_dri_path: P) -> Vec<IpDieEntry> {
+        Self::from_file(debug_dri_path.into().join(DEBUGFS_NAME))
+            .map(|bin| bin.dies)
+            .unwrap_or_default()
+    }
+}
+
+#[test]
+fn test_parse_ip_discovery_binary() {
+    fn put<T: Copy>(bin: &mut [u8], offset: usize, v: &[T]) {
+        let b = unsafe { core::slice::from_raw_parts(v.as_ptr() as *const u8, core::mem::size_of_val(v)) };
+        bin[offset..offset+b.len()].copy_from_slice(b);
+    }
+
+    let mut bin = vec![0u8; 0x400];
+    let ihdr_offset = 0x40;
+    let die_offset = 0x100;
+    let gc_offset = 0x200;
+
+    put(&mut bin, 0, &[BINARY_SIGNATURE]);
+    put(&mut bin, 4, &[1u16, 0]);
+    put(&mut bin, 10, &[0x400u16]);
+    put(&mut bin, 12, &[ihdr_offset as u16]);
+    put(&mut bin, 12 + 8, &[gc_offset as u16]);
+
+    put(&mut bin, ihdr_offset, &[DISCOVERY_TABLE_SIGNATURE]);
+    put(&mut bin, ihdr_offset + 4, &[3u16]);
+    put(&mut bin, ihdr_offset + 12, &[1u16, 0, die_offset as u16]);
+
+    put(&mut bin, die_offset, &[0u16, 3]);
+    /* GC 11.0.0, SDMA0 6.0.0 * 2 */
+    put(&mut bin, die_offset + 4, &[11u16]);
+    put(&mut bin, die_offset + 6, &[0u8, 2, 11, 0, 0, 0]);
+    put(&mut bin, die_offset + 12, &[0x1260u32, 0xA000]);
+    put(&mut bin, die_offset + 20, &[42u16]);
+    put(&mut bin, die_offset + 22, &[1u8, 1, 6, 0, 0, 0]);
+    put(&mut bin, die_offset + 28, &[0x1260u32]);
+    put(&mut bin, die_offset + 32, &[42u16]);
+    /* sub_revision 1, variant 2 */
+    put(&mut bin, die_offset + 34, &[0u8, 1, 6, 0, 0, 0x21]);
+    put(&mut bin, die_offset + 40, &[0x1260u32]);
+
+    put(&mut bin, gc_offset, &[GC_TABLE_ID]);
+    put(&mut bin, gc_offset + 4, &[1u16, 0]);
+    put(&mut bin, gc_offset + 12, &[6u32, 4, 4, 4]);
+    put(&mut bin, gc_offset + 12 + 4 * 16, &[2u32]);
+
+    let checksum = |bin: &mut [u8]| {
+        let sum = bin[10..].iter().fold(0u16, |sum, v| sum.wrapping_add(*v as u16));
+        put(bin, 8, &[sum]);
+    };
+
+    checksum(&mut bin);
+
+    let ip = IpDiscoveryBinary::parse(&bin).unwrap();
+
+    assert!(ip.binary_checksum_valid);
+    assert_eq!(ip.dies.len(), 1);
+
+    let ip_hw_ids = &ip.dies[0].ip_hw_ids;
+
+    assert_eq!(ip_hw_ids[0].hw_id, HwId::GC);
+    assert_eq!(ip_hw_ids[0].instances[0].version(), (11, 0, 0));
+    assert_eq!(ip_hw_ids[0].instances[0].base_address, vec![0x1260, 0xA000]);
+    assert_eq!(ip_hw_ids[1].hw_id, HwId::SDMA0);
+    assert_eq!(ip_hw_ids[1].instances.len(), 2);
+    assert_eq!(ip_hw_ids[1].instances[1].num_instance, 1);
+    assert_eq!(ip_hw_ids[1].instances[0].harvest, 2);
+
+    let gc_info = ip.gc_info.unwrap();
+
+    assert_eq!(gc_info.num_se(), Some(6));
+    assert_eq!(gc_info.num_cu_per_sa(), Some(16));
+    assert_eq!(gc_info.num_sa_per_se(), Some(2));
+
+    /* the low nibble is `harvest` in version 1 and 2 */
+    put(&mut bin, ihdr_offset + 4, &[2u16]);
+    checksum(&mut bin);
+
+    let ip = IpDiscoveryBinary::parse(&bin).unwrap();
+
+    assert_eq!(ip.dies[0].ip_hw_ids[1].instances[0].harvest, 1);
+
+    /* wgp0 + wgp1 overflows */
+    put(&mut bin, gc_offset + 16, &[u32::MAX, 1]);
+    checksum(&mut bin);
+
+    assert_eq!(IpDiscoveryBinary::parse(&bin).unwrap().gc_info.unwrap().num_cu_per_sa(), None);
+}
@@ -0,0 +1,119 @@
+use crate::AMDGPU::{HwId, IpDieEntry};
+
+/* ref: drivers/gpu/drm/amd/include/soc15_common.h, `SOC15_REG_OFFSET` */
+/* ref: drivers/gpu/drm/amd/include/asic_reg/gc/gc_*_offset.h */
+
+/// Register of SoC15+ (GFX9 or later) GPUs,
+/// the address is relative to the base address of the IP segment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IpRegister {
+    pub hw_id: HwId,
+    /// `*_BASE_IDX`
+    pub segment: usize,
+    /// dword offset from the segment base
+    pub offset: u32,
+}
+
+/// GRBM status registers of GC, the offsets depend on the GC version
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GcRegister {
+    GRBM_STATUS,
+    GRBM_STATUS2,
+    GRBM_STATUS_SE0,
+    GRBM_STATUS_SE1,
+    GRBM_STATUS_SE2,
+    GRBM_STATUS_SE3,
+}
+
+impl GcRegister {
+    /// dword offset from GC segment 0, `None` for GC versions other than GC 9.x to GC 12.x
+    pub fn offset(&self, gc: (u8, u8, u8)) -> Option<u32> {
+        /* gc_9_*_offset.h: `mmGRBM_STATUS` is `0x0004` (GC segment 0 is at `0x2000`) */
+        let gfx9 = match self {
+            Self::GRBM_STATUS => 0x0004,
+            Self::GRBM_STATUS2 => 0x0002,
+            Self::GRBM_STATUS_SE0 => 0x0005,
+            Self::GRBM_STATUS_SE1 => 0x0006,
+            Self::GRBM_STATUS_SE2 => 0x000E,
+            Self::GRBM_STATUS_SE3 => 0x000F,
+        };
+
+        match gc.0 {
+            9 => Some(gfx9),
+            /* gc_{10,11,12}_*_offset.h: `0x0DA0` is added (GC segment 0 is at `0x1260`) */
+            10..=12 => Some(0x0DA0 + gfx9),
+            _ => None,
+        }
+    }
+
+    pub fn ip_register(&self, gc: (u8, u8, u8)) -> Option<IpRegister> {
+        Some(IpRegister { hw_id: HwId::GC, segment: 0, offset: self.offset(gc)? })
+    }
+}
+
+impl IpDieEntry {
+    /// dword offset of `segment` of the IP instance, from `base_addr` in sysfs
+    /// (or the base addresses in the IP discovery binary)
+    pub fn base_address(&self, hw_id: HwId, instance: usize, segment: usize) -> Option<u32> {
+        let ip = self.ip_hw_ids.iter().find(|ip| ip.hw_id == hw_id)?;
+        let inst = ip.instances.iter().find(|inst| inst.num_instance as usize == instance)?;
+
+        inst.base_address.get(segment).copied()
+    }
+
+    /// Absolute MMIO dword offset for [crate::AMDGPU::DeviceHandle::read_mm_registers]
+    pub fn register_offset(&self, reg: IpRegister, instance: usize) -> Option<u32> {
+        let base = self.base_address(reg.hw_id, instance, reg.segment)?;
+
+        base.checked_add(reg.offset)
+    }
+
+    /// [Self::register_offset] with the offset for the version of the GC instance
+    pub fn gc_register_offset(&self, reg: GcRegister, instance: usize) -> Option<u32> {
+        let ip = self.ip_hw_ids.iter().find(|ip| ip.hw_id == HwId::GC)?;
+        let inst = ip.instances.iter().find(|inst| inst.num_instance as usize == instance)?;
+
+        self.register_offset(reg.ip_register(inst.version())?, instance)
+    }
+}
+
+#[test]
+fn test_register_offset() {
+    use crate::AMDGPU::{IpHwId, IpHwInstance};
+
+    let die = |major: u8, base_address: Vec<u32>| {
+        let gc = IpHwInstance {
+            hw_id: HwId::GC,
+            num_instance: 0,
+            major,
+            minor: 0,
+            revision: 0,
+            harvest: 0,
+            num_base_addresses: base_address.len() as isize,
+            base_address,
+        };
+
+        IpDieEntry {
+            die_id: 0,
+            ip_hw_ids: vec![IpHwId { hw_id: HwId::GC, instances: vec![gc] }],
+        }
+    };
+
+    let gc9 = die(9, vec![0x2000, 0xA000]);
+    let gc11 = die(11, vec![0x1260, 0xA000]);
+
+    assert_eq!(gc11.base_address(HwId::GC, 0, 1), Some(0xA000));
+    assert_eq!(gc9.gc_register_offset(GcRegister::GRBM_STATUS, 0), Some(0x2004));
+    assert_eq!(gc11.gc_register_offset(GcRegister::GRBM_STATUS, 0), Some(0x2004));
+    assert_eq!(gc9.gc_register_offset(GcRegister::GRBM_STATUS_SE3, 0), Some(0x200F));
+    assert_eq!(gc11.gc_register_offset(GcRegister::GRBM_STATUS_SE3, 0), Some(0x200F));
+    assert_eq!(gc11.gc_register_offset(GcRegister::GRBM_STATUS, 1), None);
+    assert_eq!(die(8, vec![0x2000]).gc_register_offset(GcRegister::GRBM_STATUS, 0), None);
+    assert_eq!(
+        gc11.register_offset(GcRegister::GRBM_STATUS2.ip_register((11, 0, 0)).unwrap(), 0),
+        Some(0x2002),
+    );
+    assert_eq!(gc11.base_address(HwId::SDMA0, 0, 0), None);
+    /* the base address from an IP discovery binary */
+    assert_eq!(die(11, vec![u32::MAX]).gc_register_offset(GcRegister::GRBM_STATUS, 0), None);
+}
@@ -0,0 +1,236 @@
+use std::fmt;
+use crate::AMDGPU::{CHIP_CLASS, DeviceHandle, GfxTargetVersion, HwId, IpDieEntry, IpHwId};
+
+/* ref: drivers/gpu/drm/amd/amdgpu/amdgpu_discovery.c */
+/* ref: drivers/gpu/drm/amd/amdkfd/kfd_device.c, `kgd2kfd_probe` */
+
+impl DeviceHandle {
+    /// From `ip_discovery/die/0/` in sysfs
+    pub fn get_ip_version_caps(&self) -> Option<IpVersionCaps> {
+        let sysfs_path = self.get_sysfs_path().ok()?;
+        let die = IpDieEntry::get_from_sysfs_with_die_id(0, sysfs_path.join("ip_discovery/die/"))?;
+
+        Some(IpVersionCaps::from_ip_die_entry(&die))
+    }
+}
+
+/// GPU configuration derived from the IP versions,
+/// so it does not depend on the [crate::AMDGPU::ASIC_NAME] table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IpVersionCaps {
+    pub gc: Option<(u8, u8, u8)>,
+    pub sdma: Option<(u8, u8, u8)>,
+    pub num_sdma_instances: usize,
+    pub vcn: Option<(u8, u8, u8)>,
+    pub num_vcn_instances: usize,
+    /// DCE/DCN (DMU)
+    pub dcn: Option<(u8, u8, u8)>,
+    pub mp0: Option<(u8, u8, u8)>,
+    pub mp1: Option<(u8, u8, u8)>,
+    /// NBIO (NBIF)
+    pub nbio: Option<(u8, u8, u8)>,
+    pub umc: Option<(u8, u8, u8)>,
+    pub num_umc_instances: usize,
+}
+
+impl IpVersionCaps {
+    pub fn from_ip_die_entry(die: &IpDieEntry) -> Self {
+        let find = |hw_id: HwId| -> Option<&IpHwId> {
+            die.ip_hw_ids.iter().find(|ip| ip.hw_id == hw_id && !ip.instances.is_empty())
+        };
+        let version = |hw_id: HwId| -> Option<(u8, u8, u8)> {
+            find(hw_id)?.instances.first().map(|inst| inst.version())
+        };
+        let num_instances = |hw_ids: &[HwId]| -> usize {
+            hw_ids.iter().filter_map(|hw_id| find(*hw_id)).map(|ip| ip.instances.len()).sum()
+        };
+
+        Self {
+            gc: version(HwId::GC),
+            sdma: version(HwId::SDMA0),
+            num_sdma_instances: num_instances(&[HwId::SDMA0, HwId::SDMA1, HwId::SDMA2, HwId::SDMA3]),
+            /* VCN_HWID == UVD_HWID */
+            vcn: version(HwId::UVD),
+            num_vcn_instances: num_instances(&[HwId::UVD]),
+            dcn: version(HwId::DMU),
+            mp0: version(HwId::MP0),
+            mp1: version(HwId::MP1),
+            nbio: version(HwId::NBIF),
+            umc: version(HwId::UMC),
+            num_umc_instances: num_instances(&[HwId::UMC]),
+        }
+    }
+
+    pub fn chip_class(&self) -> CHIP_CLASS {
+        match self.gc {
+            Some((12, _, _)) => CHIP_CLASS::GFX12,
+            Some((11, 5, _)) => CHIP_CLASS::GFX11_5,
+            Some((11, _, _)) => CHIP_CLASS::GFX11,
+            Some((10, 3, _)) => CHIP_CLASS::GFX10_3,
+            Some((10, _, _)) => CHIP_CLASS::GFX10,
+            Some((9, _, _)) => CHIP_CLASS::GFX9,
+            Some((8, _, _)) => CHIP_CLASS::GFX8,
+            Some((7, _, _)) => CHIP_CLASS::GFX7,
+            Some((6, _, _)) => CHIP_CLASS::GFX6,
+            _ => CHIP_CLASS::CLASS_UNKNOWN,
+        }
+    }
+
+    /// GFX target version (e.g. gfx1100) as KFD.
+    /// Unlisted GC versions are mapped as `major.minor.revision`.
+    pub fn gfx_target_version(&self) -> Option<GfxTargetVersion> {
+        let v = match self.gc? {
+            (9, 0, 1) => 90000,
+            (9, 1, 0) |
+            (9, 2, 2) => 90002,
+            (9, 2, 1) => 90004,
+            (9, 3, 0) => 90012,
+            (9, 4, 0) => 90006,
+            (9, 4, 1) => 90008,
+            (9, 4, 2) => 90010,
+            (9, 4, 3) |
+            (9, 4, 4) => 90402,
+            (10, 1, 10) => 100100,
+            (10, 1, 2) => 100101,
+            (10, 1, 1) => 100102,
+            (10, 1, 3) |
+            (10, 1, 4) => 100103,
+            (10, 3, 0) => 100300,
+            (10, 3, 2) => 100301,
+            (10, 3, 4) => 100302,
+            (10, 3, 1) => 100303,
+            (10, 3, 5) => 100304,
+            (10, 3, 3) => 100305,
+            (10, 3, 6) |
+            (10, 3, 7) => 100306,
+            (11, 0, 3) => 110001,
+            (11, 0, 1) |
+            (11, 0, 4) => 110003,
+            (major, minor, rev) => return Some(GfxTargetVersion::from((major as u32, minor as u32, rev as u32))),
+        };
+
+        Some(GfxTargetVersion::from(v))
+    }
+
+    /// ref: `amdgpu_discovery_set_mm_ip_blocks`
+    pub fn vcn_ip_block(&self) -> Option<VcnIpBlock> {
+        let block = match self.vcn? {
+            (1, 0, _) => VcnIpBlock::V1_0,
+            (2, 0, _) |
+            (2, 2, _) => VcnIpBlock::V2_0,
+            (2, 5, _) => VcnIpBlock::V2_5,
+            (2, 6, _) => VcnIpBlock::V2_6,
+            (3, _, _) => VcnIpBlock::V3_0,
+            (4, 0, 3) => VcnIpBlock::V4_0_3,
+            (4, 0, 5) |
+            (4, 0, 6) => VcnIpBlock::V4_0_5,
+            (4, _, _) => VcnIpBlock::V4_0,
+            (5, 0, 0) => VcnIpBlock::V5_0_0,
+            (5, 0, 1) => VcnIpBlock::V5_0_1,
+            (major, minor, rev) => VcnIpBlock::Unknown(major, minor, rev),
+        };
+
+        Some(block)
+    }
+
+    /// ref: `amdgpu_discovery_set_smu_ip_blocks`, it switches on the MP1 version
+    pub fn smu_ip_block(&self) -> Option<SmuIpBlock> {
+        let block = match self.mp1? {
+            /* Arcturus (GC 9.4.1) shares MP1 11.0.2 with Vega20, but uses SMU v11 */
+            (11, 0, 2) if self.gc == Some((9, 4, 1)) => SmuIpBlock::V11,
+            /* Vega10, Raven, Vega20 */
+            (9, 0, 0) |
+            (10, 0, _) |
+            (11, 0, 2) => SmuIpBlock::PowerPlay,
+            (11, _, _) => SmuIpBlock::V11,
+            (12, _, _) => SmuIpBlock::V12,
+            (13, _, _) => SmuIpBlock::V13,
+            (14, _, _) => SmuIpBlock::V14,
+            (major, minor, rev) => SmuIpBlock::Unknown(major, minor, rev),
+        };
+
+        Some(block)
+    }
+
+    /// DCN (DCE IP version 1.0 or later)
+    pub fn has_dcn(&self) -> bool {
+        self.dcn.is_some_and(|(major, _, _)| major != 0)
+    }
+}
+
+/// VCN IP block used by the kernel driver
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VcnIpBlock {
+    V1_0,
+    V2_0,
+    V2_5,
+    V2_6,
+    V3_0,
+    V4_0,
+    V4_0_3,
+    V4_0_5,
+    V5_0_0,
+    V5_0_1,
+    Unknown(u8, u8, u8),
+}
+
+impl fmt::Display for VcnIpBlock {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// SMU IP block used by the kernel driver
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmuIpBlock {
+    /// powerplay (hwmgr)
+    PowerPlay,
+    V11,
+    V12,
+    V13,
+    V14,
+    Unknown(u8, u8, u8),
+}
+
+impl fmt::Display for SmuIpBlock {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[test]
+fn test_ip_version_caps() {
+    let caps = IpVersionCaps {
+        gc: Some((11, 0, 3)),
+        sdma: Some((6, 0, 3)),
+        num_sdma_instances: 2,
+        vcn: Some((4, 0, 0)),
+        num_vcn_instances: 2,
+        dcn: Some((3, 2, 0)),
+        mp0: Some((13, 0, 10)),
+        mp1: Some((13, 0, 10)),
+        nbio: Some((4, 3, 0)),
+        umc: Some((8, 10, 0)),
+        num_umc_instances: 16,
+    };
+
+    assert_eq!(caps.chip_class(), CHIP_CLASS::GFX11);
+    assert_eq!(caps.gfx_target_version().unwrap().to_string(), "gfx1101");
+    assert_eq!(caps.vcn_ip_block(), Some(VcnIpBlock::V4_0));
+    assert_eq!(caps.smu_ip_block(), Some(SmuIpBlock::V13));
+    assert!(caps.has_dcn());
+
+    /* Vega20, Arcturus */
+    let vega20 = IpVersionCaps { gc: Some((9, 4, 0)), mp0: Some((11, 0, 2)), mp1: Some((11, 0, 2)), ..caps };
+
+    assert_eq!(vega20.smu_ip_block(), Some(SmuIpBlock::PowerPlay));
+    let arcturus = IpVersionCaps { gc: Some((9, 4, 1)), mp0: Some((11, 0, 4)), mp1: Some((11, 0, 2)), ..caps };
+
+    assert_eq!(arcturus.smu_ip_block(), Some(SmuIpBlock::V11));
+
+    /* not in the table */
+    let caps = IpVersionCaps { gc: Some((12, 1, 0)), ..caps };
+
+    assert_eq!(caps.chip_class(), CHIP_CLASS::GFX12);
+    assert_eq!(caps.gfx_target_version().unwrap().to_string(), "gfx1210");
+}
@@ -31,6 +31,9 @@ pub use chip_class::*;
 mod gpu_info;
 pub use gpu_info::*;
 
+mod shader_topology;
+pub use shader_topology::*;
+
 mod gfx_target_version;
 pub use gfx_target_version::GfxTargetVersion;
 
@@ -46,6 +49,9 @@ pub use throttle_status::*;
 mod hwmon_temp;
 pub use hwmon_temp::*;
 
+mod hwmon_sensor;
+pub use hwmon_sensor::*;
+
 mod power_cap;
 pub use power_cap::*;
 
@@ -58,19 +64,70 @@ pub use dpm_forced_level::*;
 mod ip_discovery;
 pub use ip_discovery::*;
 
+mod ip_discovery_binary;
+pub use ip_discovery_binary::*;
+
+mod ip_version_caps;
+pub use ip_version_caps::*;
+
+mod ip_register;
+pub use ip_register::*;
+
 mod ras_features;
 pub use ras_features::*;
 
+mod ras_report;
+pub use ras_report::*;
+
+mod ras_ctrl;
+pub use ras_ctrl::*;
+
 mod dpm;
 pub use dpm::*;
 
+mod pp_features;
+pub use pp_features::*;
+
 pub(crate) fn parse_hwmon<T: std::str::FromStr, P: Into<std::path::PathBuf>>(path: P) -> Option<T> {
     std::fs::read_to_string(path.into()).ok()
         .and_then(|file| file.trim_end().parse::<T>().ok())
 }
 
+/// Read `T` from `bytes[offset..]`, `None` if less than `size_of::<T>()` bytes are left
+pub(crate) fn read_struct<T>(bytes: &[u8], offset: usize) -> Option<T> {
+    read_struct_with_size(bytes, offset, core::mem::size_of::<T>())
+}
+
+/// Read `size` bytes (up to `size_of::<T>()`) from `offset` into `T`, the rest is zero-filled.
+/// `None` if less than `size` bytes are left
+pub(crate) fn read_struct_with_size<T>(bytes: &[u8], offset: usize, size: usize) -> Option<T> {
+    let size = size.min(core::mem::size_of::<T>());
+    let b = bytes.get(offset..)?.get(..size)?;
+
+    unsafe {
+        let mut t = core::mem::MaybeUninit::<T>::zeroed();
+
+        core::ptr::copy_nonoverlapping(
+            b.as_ptr(),
+            t.as_mut_ptr() as *mut u8,
+            size,
+        );
+
+        Some(t.assume_init())
+    }
+}
+
 mod vbios;
 mod vbios_parser;
+mod vbios_vram_info;
+mod vbios_gfx_info;
+mod vbios_integrated_system_info;
+mod vbios_smu_info;
+mod vbios_display_object;
+mod vbios_table_list;
+mod vbios_disasm;
+mod vbios_legacy;
+mod vbios_pci_rom;
 mod video_caps;
 mod hw_ip;
 mod fw_version;
@@ -79,9 +136,21 @@ mod sensor_info;
 pub mod pp_table;
 pub use pp_table::PPTable;
 
+mod table_diff;
+pub use table_diff::*;
+
 pub mod VBIOS {
     pub use super::vbios::*;
     pub use super::vbios_parser::*;
+    pub use super::vbios_vram_info::*;
+    pub use super::vbios_gfx_info::*;
+    pub use super::vbios_integrated_system_info::*;
+    pub use super::vbios_smu_info::*;
+    pub use super::vbios_display_object::*;
+    pub use super::vbios_table_list::*;
+    pub use super::vbios_disasm::*;
+    pub use super::vbios_legacy::*;
+    pub use super::vbios_pci_rom::*;
 }
 
 /// # Video Encode/Decode Capabilities
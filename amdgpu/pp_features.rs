@@ -0,0 +1,276 @@
+use crate::AMDGPU::DeviceHandle;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+const SYSFS_NAME: &str = "pp_features";
+
+impl DeviceHandle {
+    pub fn get_pp_features(&self) -> io::Result<PpFeatures> {
+        let sysfs_path = self.get_sysfs_path_io()?;
+
+        PpFeatures::get_from_sysfs(sysfs_path)
+    }
+
+    /// Requires root privileges.
+    pub fn set_pp_features_mask(&self, mask: u64) -> io::Result<()> {
+        let sysfs_path = self.get_sysfs_path_io()?;
+
+        PpFeatures::set_mask_to_sysfs(sysfs_path, mask)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SmuFeature {
+    pub bit: u8,
+    pub name: String,
+    pub enabled: bool,
+}
+
+/// SMU feature mask (`pp_features`)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PpFeatures {
+    pub mask: u64,
+    pub features: Vec<SmuFeature>,
+}
+
+impl PpFeatures {
+    pub fn get_from_sysfs<P: Into<PathBuf>>(sysfs_path: P) -> io::Result<Self> {
+        let s = std::fs::read_to_string(sysfs_path.into().join(SYSFS_NAME))?;
+
+        Self::parse(&s).ok_or(io::Error::other("Parse Error"))
+    }
+
+    /// The kernel enables the features set in `mask` and disables the rest.  
+    /// Requires root privileges.
+    pub fn set_mask_to_sysfs<P: Into<PathBuf>>(sysfs_path: P, mask: u64) -> io::Result<()> {
+        std::fs::write(sysfs_path.into().join(SYSFS_NAME), format!("{mask:#x}"))
+    }
+
+    /// Parse the output of `pp_features`.  
+    /// ref: drivers/gpu/drm/amd/pm/swsmu/smu_cmn.c, `smu_cmn_get_pp_feature_mask`  
+    /// ref: drivers/gpu/drm/amd/pm/powerplay/hwmgr/vega20_hwmgr.c, `vega20_get_ppfeature_status`
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut lines = s.lines();
+        let first = lines.next()?;
+
+        let (mask, features) = if let Some(tmp) = first.strip_prefix("features high: ") {
+            // "features high: 0x00000623 low: 0xb3cdaffb"
+            let (high, low) = tmp.split_once(" low: ")?;
+            let [high, low] = [high, low].map(|v| {
+                u32::from_str_radix(v.trim().trim_start_matches("0x"), 16).ok()
+            });
+            let mask = ((high? as u64) << 32) | low? as u64;
+            // "00. DPM_PREFETCHER        ( 0) : enabled"
+            let features = lines.skip(1).filter_map(|l| {
+                let (_, l) = l.split_once(". ")?;
+                let (name, l) = l.split_once('(')?;
+                let (bit, state) = l.split_once(')')?;
+
+                Some(SmuFeature {
+                    bit: bit.trim().parse().ok()?,
+                    name: name.trim().to_string(),
+                    enabled: state.trim_start_matches([' ', ':']).starts_with("enabled"),
+                })
+            }).collect();
+
+            (mask, features)
+        } else if let Some(tmp) = first.strip_prefix("Current ppfeatures: ") {
+            // "Current ppfeatures: 0x0000000019f0e3cf"
+            let mask = u64::from_str_radix(tmp.trim().trim_start_matches("0x"), 16).ok()?;
+            // "DPM_PREFETCHER      0x0000000000000001  Y"
+            let features = lines.skip(1).filter_map(|l| {
+                let mut split = l.split_whitespace();
+                let [name, bitmask, state] = [split.next()?, split.next()?, split.next()?];
+                let bitmask = u64::from_str_radix(bitmask.trim_start_matches("0x"), 16).ok()?;
+
+                Some(SmuFeature {
+                    bit: bitmask.trailing_zeros() as u8,
+                    name: name.to_string(),
+                    enabled: state == "Y",
+                })
+            }).collect();
+
+            (mask, features)
+        } else {
+            return None;
+        };
+
+        Some(Self { mask, features })
+    }
+
+    /// Decode the mask with the feature bit names of `smu_ver` (MP1 IP version).
+    pub fn decode_with_smu_version(mask: u64, smu_ver: (u8, u8, u8)) -> Option<Self> {
+        let names = smu_feature_names(smu_ver)?;
+        let features = names.iter().enumerate().map(|(bit, name)| SmuFeature {
+            bit: bit as u8,
+            name: name.to_string(),
+            enabled: ((mask >> bit) & 0b1) == 1,
+        }).collect();
+
+        Some(Self { mask, features })
+    }
+
+    pub fn get_feature(&self, name: &str) -> Option<&SmuFeature> {
+        self.features.iter().find(|f| f.name == name)
+    }
+
+    pub fn is_enabled(&self, name: &str) -> Option<bool> {
+        self.get_feature(name).map(|f| f.enabled)
+    }
+
+    pub fn enabled_features(&self) -> Vec<&SmuFeature> {
+        self.features.iter().filter(|f| f.enabled).collect()
+    }
+
+    /// Returns a new mask with the feature enabled or disabled,
+    /// to be passed to [PpFeatures::set_mask_to_sysfs].
+    pub fn mask_with(&self, name: &str, enable: bool) -> Option<u64> {
+        let bit = self.get_feature(name)?.bit;
+
+        if enable {
+            Some(self.mask | (1 << bit))
+        } else {
+            Some(self.mask & !(1 << bit))
+        }
+    }
+
+    /// Returns features whose enabled state differs: `(feature in self, enabled state in other)`
+    pub fn diff<'a>(&'a self, other: &PpFeatures) -> Vec<(&'a SmuFeature, bool)> {
+        self.features.iter().filter_map(|f| {
+            let other_enabled = ((other.mask >> f.bit) & 0b1) == 1;
+
+            (f.enabled != other_enabled).then_some((f, other_enabled))
+        }).collect()
+    }
+}
+
+impl fmt::Display for SmuFeature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:<24} ({:2}) : {}",
+            self.name,
+            self.bit,
+            if self.enabled { "enabled" } else { "disabled" },
+        )
+    }
+}
+
+/// Feature bit names for each SMU (MP1) version
+pub fn smu_feature_names(smu_ver: (u8, u8, u8)) -> Option<&'static [&'static str; 64]> {
+    let names = match smu_ver {
+        (11, 0, 0) | /* Navi10 */
+        (11, 0, 5) | /* Navi14 */
+        (11, 0, 9) /* Navi12 */
+            => &SMU_V11_0_0_FEATURES,
+        (11, 0, 7) | /* Navi21 */
+        (11, 0, 11) | /* Navi22 */
+        (11, 0, 12) | /* Navi23 */
+        (11, 0, 13) /* Navi24 */
+            => &SMU_V11_0_7_FEATURES,
+        (13, 0, 0) | /* Navi31 */
+        (13, 0, 10)
+            => &SMU_V13_0_0_FEATURES,
+        (13, 0, 7) /* Navi32, Navi33 */
+            => &SMU_V13_0_7_FEATURES,
+        _ => return None,
+    };
+
+    Some(names)
+}
+
+#[test]
+fn test_pp_features_parse() {
+    let s = "features high: 0x00000623 low: 0xb3cdaffb\n\
+        No. Feature               Bit : State\n\
+        00. DPM_PREFETCHER        ( 0) : enabled\n\
+        01. DPM_GFXCLK            ( 1) : enabled\n\
+        02. DPM_GFX_GPO           ( 2) : disabled\n";
+    let ppf = PpFeatures::parse(s).unwrap();
+
+    assert_eq!(ppf.mask, 0x00000623_b3cdaffb);
+    assert_eq!(ppf.features.len(), 3);
+    assert_eq!(ppf.is_enabled("DPM_GFX_GPO"), Some(false));
+    assert_eq!(ppf.mask_with("DPM_GFXCLK", false), Some(0x00000623_b3cdaff9));
+}
+
+/* ref: wrapper/smu11_driver_if_navi10.h */
+const SMU_V11_0_0_FEATURES: [&str; 64] = [
+    "DPM_PREFETCHER", "DPM_GFXCLK", "DPM_GFX_PACE", "DPM_UCLK",
+    "DPM_SOCCLK", "DPM_MP0CLK", "DPM_LINK", "DPM_DCEFCLK",
+    "MEM_VDDCI_SCALING", "MEM_MVDD_SCALING", "DS_GFXCLK", "DS_SOCCLK",
+    "DS_LCLK", "DS_DCEFCLK", "DS_UCLK", "GFX_ULV",
+    "FW_DSTATE", "GFXOFF", "BACO", "VCN_PG",
+    "JPEG_PG", "USB_PG", "RSMU_SMN_CG", "PPT",
+    "TDC", "GFX_EDC", "APCC_PLUS", "GTHR",
+    "ACDC", "VR0HOT", "VR1HOT", "FW_CTF",
+    "FAN_CONTROL", "THERMAL", "GFX_DCS", "RM",
+    "LED_DISPLAY", "GFX_SS", "OUT_OF_BAND_MONITOR", "TEMP_DEPENDENT_VMIN",
+    "MMHUB_PG", "ATHUB_PG", "APCC_DFLL", "SPARE_43",
+    "SPARE_44", "SPARE_45", "SPARE_46", "SPARE_47",
+    "SPARE_48", "SPARE_49", "SPARE_50", "SPARE_51",
+    "SPARE_52", "SPARE_53", "SPARE_54", "SPARE_55",
+    "SPARE_56", "SPARE_57", "SPARE_58", "SPARE_59",
+    "SPARE_60", "SPARE_61", "SPARE_62", "SPARE_63",
+];
+
+/* ref: wrapper/smu11_driver_if_sienna_cichlid.h */
+const SMU_V11_0_7_FEATURES: [&str; 64] = [
+    "DPM_PREFETCHER", "DPM_GFXCLK", "DPM_GFX_GPO", "DPM_UCLK",
+    "DPM_FCLK", "DPM_SOCCLK", "DPM_MP0CLK", "DPM_LINK",
+    "DPM_DCEFCLK", "DPM_XGMI", "MEM_VDDCI_SCALING", "MEM_MVDD_SCALING",
+    "DS_GFXCLK", "DS_SOCCLK", "DS_FCLK", "DS_LCLK",
+    "DS_DCEFCLK", "DS_UCLK", "GFX_ULV", "FW_DSTATE",
+    "GFXOFF", "BACO", "MM_DPM_PG", "SPARE_23",
+    "PPT", "TDC", "APCC_PLUS", "GTHR",
+    "ACDC", "VR0HOT", "VR1HOT", "FW_CTF",
+    "FAN_CONTROL", "THERMAL", "GFX_DCS", "RM",
+    "LED_DISPLAY", "GFX_SS", "OUT_OF_BAND_MONITOR", "TEMP_DEPENDENT_VMIN",
+    "MMHUB_PG", "ATHUB_PG", "APCC_DFLL", "DF_SUPERV",
+    "RSMU_SMN_CG", "DF_CSTATE", "2_STEP_PSTATE", "SMNCLK_DPM",
+    "PERLINK_GMIDOWN", "GFX_EDC", "GFX_PER_PART_VMIN", "SMART_SHIFT",
+    "APT", "SPARE_53", "SPARE_54", "SPARE_55",
+    "SPARE_56", "SPARE_57", "SPARE_58", "SPARE_59",
+    "SPARE_60", "SPARE_61", "SPARE_62", "SPARE_63",
+];
+
+/* ref: wrapper/smu13_driver_if_v13_0_0.h */
+const SMU_V13_0_0_FEATURES: [&str; 64] = [
+    "FW_DATA_READ", "DPM_GFXCLK", "DPM_GFX_POWER_OPTIMIZER", "DPM_UCLK",
+    "DPM_FCLK", "DPM_SOCCLK", "DPM_MP0CLK", "DPM_LINK",
+    "DPM_DCN", "VMEMP_SCALING", "VDDIO_MEM_SCALING", "DS_GFXCLK",
+    "DS_SOCCLK", "DS_FCLK", "DS_LCLK", "DS_DCFCLK",
+    "DS_UCLK", "GFX_ULV", "FW_DSTATE", "GFXOFF",
+    "BACO", "MM_DPM", "SOC_MPCLK_DS", "BACO_MPCLK_DS",
+    "THROTTLERS", "SMARTSHIFT", "GTHR", "ACDC",
+    "VR0HOT", "FW_CTF", "FAN_CONTROL", "GFX_DCS",
+    "GFX_READ_MARGIN", "LED_DISPLAY", "GFXCLK_SPREAD_SPECTRUM", "OUT_OF_BAND_MONITOR",
+    "OPTIMIZED_VMIN", "GFX_IMU", "BOOT_TIME_CAL", "GFX_PCC_DFLL",
+    "SOC_CG", "DF_CSTATE", "GFX_EDC", "BOOT_POWER_OPT",
+    "CLOCK_POWER_DOWN_BYPASS", "DS_VCN", "BACO_CG", "MEM_TEMP_READ",
+    "ATHUB_MMHUB_PG", "SOC_PCC", "EDC_PWRBRK", "BOMXCO_SVI3_PROG",
+    "SPARE_52", "SPARE_53", "SPARE_54", "SPARE_55",
+    "SPARE_56", "SPARE_57", "SPARE_58", "SPARE_59",
+    "SPARE_60", "SPARE_61", "SPARE_62", "SPARE_63",
+];
+
+/* ref: wrapper/smu13_driver_if_v13_0_7.h */
+const SMU_V13_0_7_FEATURES: [&str; 64] = [
+    "FW_DATA_READ", "DPM_GFXCLK", "DPM_GFX_POWER_OPTIMIZER", "DPM_UCLK",
+    "DPM_FCLK", "DPM_SOCCLK", "DPM_MP0CLK", "DPM_LINK",
+    "DPM_DCN", "VMEMP_SCALING", "VDDIO_MEM_SCALING", "DS_GFXCLK",
+    "DS_SOCCLK", "DS_FCLK", "DS_LCLK", "DS_DCFCLK",
+    "DS_UCLK", "GFX_ULV", "FW_DSTATE", "GFXOFF",
+    "BACO", "MM_DPM", "SOC_MPCLK_DS", "BACO_MPCLK_DS",
+    "THROTTLERS", "SMARTSHIFT", "GTHR", "ACDC",
+    "VR0HOT", "FW_CTF", "FAN_CONTROL", "GFX_DCS",
+    "GFX_READ_MARGIN", "LED_DISPLAY", "GFXCLK_SPREAD_SPECTRUM", "OUT_OF_BAND_MONITOR",
+    "OPTIMIZED_VMIN", "GFX_IMU", "BOOT_TIME_CAL", "GFX_PCC_DFLL",
+    "SOC_CG", "DF_CSTATE", "GFX_EDC", "BOOT_POWER_OPT",
+    "CLOCK_POWER_DOWN_BYPASS", "DS_VCN", "BACO_CG", "MEM_TEMP_READ",
+    "ATHUB_MMHUB_PG", "SOC_PCC", "EDC_PWRBRK", "SPARE_51",
+    "SPARE_52", "SPARE_53", "SPARE_54", "SPARE_55",
+    "SPARE_56", "SPARE_57", "SPARE_58", "SPARE_59",
+    "SPARE_60", "SPARE_61", "SPARE_62", "SPARE_63",
+];
@@ -0,0 +1,370 @@
+use core::mem::size_of;
+use std::io;
+use std::path::PathBuf;
+use crate::AMDGPU::DeviceHandle;
+use super::{
+    PPTable,
+    PPTableFanDefaults,
+    OdIds,
+    SMU_11_0_OD_IDS,
+    SMU_11_0_7_OD_IDS,
+    SMU_13_0_0_OD_IDS,
+};
+use crate::bindings::ppt::smu_v13_0_0_ppt::TEMP_e_TEMP_HOTSPOT;
+
+const SYSFS_NAME: &str = "pp_table";
+
+impl DeviceHandle {
+    /// Upload a PowerPlay table to `pp_table` (soft PPTable).
+    /// The driver has no reset path, keep the original table to write it back.
+    /// Requires root privileges.
+    pub fn write_pp_table(&self, bytes: &[u8]) -> io::Result<()> {
+        let sysfs_path = self.get_sysfs_path_io()?;
+
+        PPTable::write_to_sysfs(sysfs_path, bytes)
+    }
+}
+
+/// Overdrive settings common to the PowerPlay tables, see [super::PPTableOdRanges]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PPTableOdSetting {
+    GfxclkFmin,
+    GfxclkFmax,
+    UclkFmin,
+    UclkFmax,
+    PowerPercentage,
+}
+
+impl PPTable {
+    fn as_bytes(&self) -> Option<&[u8]> {
+        fn bytes<T>(t: &T) -> &[u8] {
+            unsafe { core::slice::from_raw_parts(t as *const T as *const u8, size_of::<T>()) }
+        }
+
+        let b = match self {
+            Self::Tonga(t) => bytes(t),
+            Self::Vega10(t) => bytes(t),
+            Self::Vega20(t) => bytes(t),
+            Self::V11_0_0(t) => bytes(t),
+            Self::V11_0_7(t) => bytes(t),
+            Self::V13_0_0(t) => bytes(t),
+            Self::V13_0_7(t) => bytes(t),
+            Self::V14_0_2(t) => bytes(t),
+            Self::Unknown(_) |
+            Self::Invalid => return None,
+        };
+
+        Some(b)
+    }
+
+    /// Serialize the table over `original` (the bytes it was decoded from).
+    /// The length of `original` is kept, and the data after the structure
+    /// (sub-tables, `smc_pptable` of Vega20/SMU 14.0.2, etc.) is preserved.
+    /// `None` for [PPTable::Unknown] and [PPTable::Invalid].
+    pub fn encode(&self, original: &[u8]) -> Option<Vec<u8>> {
+        let b = self.as_bytes()?;
+        let mut out = original.to_vec();
+        let len = b.len().min(out.len());
+
+        out[..len].copy_from_slice(&b[..len]);
+
+        Some(out)
+    }
+
+    /// The kernel rejects a table whose `structuresize` in the header does not match the size written.
+    /// ref: drivers/gpu/drm/amd/pm/swsmu/amdgpu_smu.c, `smu_sys_set_pp_table`
+    pub fn check_before_upload(bytes: &[u8]) -> io::Result<()> {
+        if bytes.is_empty() {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "PPTable is empty"));
+        }
+
+        let header = Self::get_header(bytes).ok_or(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "PPTable is smaller than the header",
+        ))?;
+
+        let structuresize = header.structuresize;
+
+        if structuresize as usize != bytes.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "PPTable size mismatch (header: {}, bytes: {})",
+                    structuresize,
+                    bytes.len(),
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Requires root privileges.
+    pub fn write_to_sysfs<P: Into<PathBuf>>(sysfs_path: P, bytes: &[u8]) -> io::Result<()> {
+        Self::check_before_upload(bytes)?;
+
+        std::fs::write(sysfs_path.into().join(SYSFS_NAME), bytes)
+    }
+}
+
+/* Setters return `false` if the table does not have the field */
+impl PPTable {
+    /// W, PPT0 AC/DC
+    pub fn set_socket_power_limit(&mut self, ac: u16, dc: u16) -> bool {
+        macro_rules! set {
+            ($smc:expr) => {{
+                let (mut a, mut d) = ({ $smc.SocketPowerLimitAc }, { $smc.SocketPowerLimitDc });
+                a[0] = ac;
+                d[0] = dc;
+                $smc.SocketPowerLimitAc = a;
+                $smc.SocketPowerLimitDc = d;
+            }};
+        }
+
+        match self {
+            Self::V11_0_0(t) => set!(t.smc_pptable),
+            Self::V11_0_7(t) => set!(t.smc_pptable),
+            Self::V13_0_0(t) => set!(t.smc_pptable.SkuTable),
+            Self::V13_0_7(t) => set!(t.smc_pptable.SkuTable),
+            _ => return false,
+        }
+
+        true
+    }
+
+    /// W
+    pub fn set_small_boost_power_limit(&mut self, small1: u16, small2: u16, boost: u16) -> bool {
+        match self {
+            Self::V11_0_0(t) => (t.small_power_limit1, t.small_power_limit2, t.boost_power_limit) = (small1, small2, boost),
+            Self::V11_0_7(t) => (t.small_power_limit1, t.small_power_limit2, t.boost_power_limit) = (small1, small2, boost),
+            Self::V13_0_0(t) => (t.small_power_limit1, t.small_power_limit2, t.boost_power_limit) = (small1, small2, boost),
+            Self::V13_0_7(t) => (t.small_power_limit1, t.small_power_limit2, t.boost_power_limit) = (small1, small2, boost),
+            Self::V14_0_2(t) => (t.small_power_limit1, t.small_power_limit2, t.boost_power_limit) = (small1, small2, boost),
+            Self::Vega20(t) => (t.usSmallPowerLimit1, t.usSmallPowerLimit2, t.usBoostPowerLimit) = (small1, small2, boost),
+            _ => return false,
+        }
+
+        true
+    }
+
+    /// A
+    pub fn set_tdc_limit(&mut self, gfx: u16, soc: u16) -> bool {
+        macro_rules! set_array {
+            ($field:expr) => {{
+                let mut v = { $field };
+                v[0] = gfx;
+                v[1] = soc;
+                $field = v;
+            }};
+        }
+
+        match self {
+            Self::V11_0_0(t) => (t.smc_pptable.TdcLimitGfx, t.smc_pptable.TdcLimitSoc) = (gfx, soc),
+            Self::V11_0_7(t) => set_array!(t.smc_pptable.TdcLimit),
+            Self::V13_0_0(t) => set_array!(t.smc_pptable.SkuTable.VrTdcLimit),
+            Self::V13_0_7(t) => set_array!(t.smc_pptable.SkuTable.VrTdcLimit),
+            _ => return false,
+        }
+
+        true
+    }
+
+    /// MHz for clocks, % for the power limit.
+    /// Tonga/Vega10 tables only have the maximums (`min` is ignored), and the power limit is `-max..=max`.
+    /// `false` if `min > max`.
+    pub fn set_od_range(&mut self, setting: PPTableOdSetting, min: i64, max: i64) -> bool {
+        if min > max { return false }
+
+        fn set<T: TryFrom<i64>>(ids: &OdIds, setting: PPTableOdSetting, od_min: &mut [T], od_max: &mut [T], min: i64, max: i64) -> bool {
+            let Some(id) = ids.get(setting) else { return false };
+            let (Ok(min), Ok(max)) = (T::try_from(min), T::try_from(max)) else { return false };
+            let (Some(dst_min), Some(dst_max)) = (od_min.get_mut(id), od_max.get_mut(id)) else { return false };
+
+            *dst_min = min;
+            *dst_max = max;
+
+            true
+        }
+
+        macro_rules! set_od {
+            ($ids:expr, $min:expr, $max:expr) => {{
+                let (mut od_min, mut od_max) = ({ $min }, { $max });
+                let r = set($ids, setting, &mut od_min, &mut od_max, min, max);
+                $min = od_min;
+                $max = od_max;
+
+                r
+            }};
+        }
+
+        macro_rules! set_legacy {
+            ($t:expr) => {{
+                /* MHz -> 10 kHz */
+                let Some(max_10khz) = max.checked_mul(100).and_then(|v| u32::try_from(v).ok()) else { return false };
+
+                match setting {
+                    PPTableOdSetting::GfxclkFmax => $t.ulMaxODEngineClock = max_10khz,
+                    PPTableOdSetting::UclkFmax => $t.ulMaxODMemoryClock = max_10khz,
+                    PPTableOdSetting::PowerPercentage => {
+                        let Ok(max) = u16::try_from(max) else { return false };
+                        $t.usPowerControlLimit = max;
+                    },
+                    _ => return false,
+                }
+
+                true
+            }};
+        }
+
+        match self {
+            Self::V11_0_0(t) => set_od!(&SMU_11_0_OD_IDS, t.overdrive_table.min, t.overdrive_table.max),
+            Self::V11_0_7(t) => set_od!(&SMU_11_0_7_OD_IDS, t.overdrive_table.min, t.overdrive_table.max),
+            Self::V13_0_0(t) => set_od!(&SMU_13_0_0_OD_IDS, t.overdrive_table.min, t.overdrive_table.max),
+            Self::V13_0_7(t) => set_od!(&SMU_13_0_0_OD_IDS, t.overdrive_table.min, t.overdrive_table.max),
+            Self::V14_0_2(t) => set_od!(&SMU_13_0_0_OD_IDS, t.overdrive_table.min, t.overdrive_table.max),
+            Self::Vega20(t) => set_od!(&SMU_11_0_OD_IDS, t.OverDrive8Table.ODSettingsMin, t.OverDrive8Table.ODSettingsMax),
+            Self::Tonga(t) => set_legacy!(t),
+            Self::Vega10(t) => set_legacy!(t),
+            Self::Unknown(_) |
+            Self::Invalid => false,
+        }
+    }
+
+    /// Only the `Some` fields are written.
+    /// The temperatures of SMU 13.0.x tables are written for the hotspot sensor, as [PPTable::summary] reads them.
+    pub fn set_fan_defaults(&mut self, fan: &PPTableFanDefaults) -> bool {
+        macro_rules! set_smu11 {
+            ($smc:expr) => {{
+                if let Some(v) = fan.target_temperature { $smc.FanTargetTemperature = v }
+                if let Some(v) = fan.start_temperature { $smc.FanStartTemp = v }
+                if let Some(v) = fan.stop_temperature { $smc.FanStopTemp = v }
+                if let Some(v) = fan.pwm_min { $smc.FanPwmMin = v }
+                if let Some(v) = fan.max_rpm { $smc.FanMaximumRpm = v }
+                if let Some(v) = fan.acoustic_limit_rpm { $smc.FanAcousticLimitRpm = v }
+                if let Some(v) = fan.zero_rpm_enable { $smc.FanZeroRpmEnable = v as u8 }
+            }};
+        }
+
+        macro_rules! set_smu13 {
+            ($sku:expr) => {{
+                let hotspot = TEMP_e_TEMP_HOTSPOT as usize;
+                let (mut target, mut start, mut stop) = ({ $sku.FanTargetTemperature }, { $sku.FanStartTemp }, { $sku.FanStopTemp });
+
+                if let Some(v) = fan.target_temperature { target[hotspot] = v }
+                if let Some(v) = fan.start_temperature { start[hotspot] = v }
+                if let Some(v) = fan.stop_temperature { stop[hotspot] = v }
+
+                $sku.FanTargetTemperature = target;
+                $sku.FanStartTemp = start;
+                $sku.FanStopTemp = stop;
+
+                if let Some(v) = fan.pwm_min { $sku.FanPwmMin = v }
+                if let Some(v) = fan.max_rpm { $sku.FanMaximumRpm = v }
+                if let Some(v) = fan.acoustic_limit_rpm { $sku.AcousticLimitRpmThreshold = v }
+                if let Some(v) = fan.zero_rpm_enable { $sku.FanZeroRpmEnable = v as u8 }
+            }};
+        }
+
+        match self {
+            Self::V11_0_0(t) => set_smu11!(t.smc_pptable),
+            Self::V11_0_7(t) => set_smu11!(t.smc_pptable),
+            Self::V13_0_0(t) => set_smu13!(t.smc_pptable.SkuTable),
+            Self::V13_0_7(t) => set_smu13!(t.smc_pptable.SkuTable),
+            _ => return false,
+        }
+
+        true
+    }
+}
+
+#[test]
+fn test_encode_and_edit() {
+    use crate::bindings::ppt::tonga_ppt::ATOM_Tonga_POWERPLAYTABLE;
+
+    let size = size_of::<ATOM_Tonga_POWERPLAYTABLE>();
+    /* header + trailing sub-table data */
+    let mut bytes = vec![0u8; size + 16];
+    let len = bytes.len() as u16;
+    bytes[0..2].copy_from_slice(&len.to_le_bytes());
+    bytes[2] = 7;
+    bytes[size..].fill(0xAB);
+
+    let mut ppt = PPTable::decode(&bytes).unwrap();
+
+    assert!(ppt.set_od_range(PPTableOdSetting::GfxclkFmax, 0, 1500));
+    assert!(ppt.set_od_range(PPTableOdSetting::PowerPercentage, -50, 50));
+    assert!(!ppt.set_od_range(PPTableOdSetting::UclkFmin, 0, 1000));
+    assert!(!ppt.set_tdc_limit(100, 50));
+
+    let encoded = ppt.encode(&bytes).unwrap();
+
+    assert_eq!(encoded.len(), bytes.len());
+    assert!(encoded[size..].iter().all(|b| *b == 0xAB));
+    assert!(PPTable::check_before_upload(&encoded).is_ok());
+    assert!(PPTable::check_before_upload(&encoded[..size]).is_err());
+    assert_eq!(
+        PPTable::write_to_sysfs("/nonexistent", &[]).unwrap_err().kind(),
+        io::ErrorKind::InvalidInput,
+    );
+
+    let PPTable::Tonga(t) = PPTable::decode(&encoded).unwrap() else { panic!() };
+
+    assert_eq!({ t.ulMaxODEngineClock }, 150_000);
+    assert_eq!({ t.usPowerControlLimit }, 50);
+}
+
+#[test]
+fn test_edit_smu11_smu13() {
+    use crate::bindings::ppt::{
+        smu_v11_0_0_ppt::smu_11_0_powerplay_table,
+        smu_v13_0_0_ppt::smu_13_0_0_powerplay_table,
+    };
+
+    let fan = PPTableFanDefaults {
+        target_temperature: Some(85),
+        start_temperature: Some(50),
+        stop_temperature: None,
+        pwm_min: Some(20),
+        max_rpm: Some(3300),
+        acoustic_limit_rpm: None,
+        zero_rpm_enable: Some(true),
+    };
+
+    for (format_revision, size) in [
+        (12, size_of::<smu_11_0_powerplay_table>()),
+        (20, size_of::<smu_13_0_0_powerplay_table>()),
+    ] {
+        let mut bytes = vec![0u8; size];
+        let len = bytes.len() as u16;
+        bytes[0..2].copy_from_slice(&len.to_le_bytes());
+        bytes[2] = format_revision;
+
+        let mut ppt = PPTable::decode(&bytes).unwrap();
+
+        assert!(ppt.set_socket_power_limit(250, 200));
+        assert!(ppt.set_tdc_limit(300, 55));
+        assert!(ppt.set_fan_defaults(&fan));
+        assert!(ppt.set_od_range(PPTableOdSetting::GfxclkFmax, 500, 3000));
+        assert!(!ppt.set_od_range(PPTableOdSetting::GfxclkFmax, 3000, 500));
+
+        let s = PPTable::decode(&ppt.encode(&bytes).unwrap()).unwrap().summary().unwrap();
+
+        assert_eq!((s.power_limit_ac, s.power_limit_dc), (Some(250), Some(200)));
+        assert_eq!((s.tdc_limit_gfx, s.tdc_limit_soc), (Some(300), Some(55)));
+        assert_eq!(s.od.gfxclk_fmax, Some(super::OdRange { min: 500, max: 3000 }));
+        assert_eq!(s.fan, PPTableFanDefaults {
+            stop_temperature: Some(0),
+            acoustic_limit_rpm: Some(0),
+            ..fan.clone()
+        });
+    }
+
+    let mut bytes = vec![0u8; size_of::<crate::bindings::ppt::tonga_ppt::ATOM_Tonga_POWERPLAYTABLE>()];
+    let len = bytes.len() as u16;
+    bytes[0..2].copy_from_slice(&len.to_le_bytes());
+    bytes[2] = 7;
+
+    let mut ppt = PPTable::decode(&bytes).unwrap();
+
+    assert!(!ppt.set_od_range(PPTableOdSetting::GfxclkFmax, 0, i64::MAX));
+}
@@ -0,0 +1,351 @@
+mod summary;
+pub use summary::*;
+mod encode;
+pub use encode::*;
+
+use core::mem::{offset_of, size_of};
+use super::read_struct;
+use crate::bindings::atom_common_table_header;
+pub use crate::bindings::ppt::{
+    smu_v11_0_0_ppt::{smu_11_0_powerplay_table, PPTable_t as PPTable_smu_11_0_t},
+    smu_v11_0_7_ppt::{smu_11_0_7_powerplay_table, PPTable_t as PPTable_smu_11_0_7_t, PPTable_beige_goby_t},
+    smu_v13_0_0_ppt::{smu_13_0_0_powerplay_table, PPTable_t as PPTable_smu_13_0_0_t},
+    smu_v13_0_7_ppt::{smu_13_0_7_powerplay_table, PPTable_t as PPTable_smu_13_0_7_t},
+    tonga_ppt::ATOM_Tonga_POWERPLAYTABLE,
+    vega10_ppt::ATOM_Vega10_POWERPLAYTABLE,
+    vega20_ppt::ATOM_Vega20_POWERPLAYTABLE,
+    smu_v14_0_2_ppt::smu_14_0_2_powerplay_table,
+};
+use crate::bindings::ppt::{
+    tonga_ppt::{
+        ATOM_Tonga_SCLK_Dependency_Record,
+        ATOM_Polaris_SCLK_Dependency_Record,
+        ATOM_Tonga_MCLK_Dependency_Record,
+        ATOM_Tonga_Voltage_Lookup_Record,
+        ATOM_Tonga_PowerTune_Table,
+    },
+    vega10_ppt::{
+        ATOM_Vega10_CLK_Dependency_Record,
+        ATOM_Vega10_GFXCLK_Dependency_Record,
+        ATOM_Vega10_GFXCLK_Dependency_Record_V2,
+        ATOM_Vega10_MCLK_Dependency_Record,
+        ATOM_Vega10_Voltage_Lookup_Record,
+        ATOM_Vega10_PowerTune_Table,
+    },
+};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PPTableDecodeError {
+    SmallerThanHeader,
+    SmallerThanStructureSizeInHeader,
+}
+
+#[derive(Debug, Clone)]
+pub enum PPTable {
+    /// Tonga, Fiji, Polaris, VegaM.
+    /// Fiji has no separate variant, its table has the same layout as Tonga (format revision 7).
+    Tonga(ATOM_Tonga_POWERPLAYTABLE),
+    Vega10(ATOM_Vega10_POWERPLAYTABLE),
+    /// Driver portion only, see [PPTable::smc_pptable_offset]
+    Vega20(ATOM_Vega20_POWERPLAYTABLE),
+    V11_0_0(smu_11_0_powerplay_table),
+    V11_0_7(smu_11_0_7_powerplay_table),
+    V13_0_0(smu_13_0_0_powerplay_table),
+    V13_0_7(smu_13_0_7_powerplay_table),
+    /// SMU 14.0.2 and 14.0.3, driver portion only, see [PPTable::smc_pptable_offset]
+    V14_0_2(smu_14_0_2_powerplay_table),
+    Unknown(atom_common_table_header),
+    Invalid,
+}
+
+impl PPTable {
+    fn get_header(bytes: &[u8]) -> Option<atom_common_table_header> {
+        read_struct(bytes, 0)
+    }
+
+    fn check_length(header: &atom_common_table_header, len: usize) -> bool {
+        header.structuresize as usize <= len
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, PPTableDecodeError> {
+        let Some(header) = Self::get_header(bytes) else { return Err(PPTableDecodeError::SmallerThanHeader) };
+
+        if !Self::check_length(&header, bytes.len()) {
+            return Err(PPTableDecodeError::SmallerThanStructureSizeInHeader);
+        }
+
+        // ref: https://github.com/sibradzic/upp/blob/master/src/upp/decode.py
+        let ppt = match header.format_revision {
+            // Navi44, Navi48: 3
+            3 => Self::V14_0_2(Self::to_pptable(bytes)),
+            // Tonga, Fiji, Polaris10/11/12, VegaM: 7
+            7 => Self::Tonga(Self::to_pptable(bytes)),
+            // Vega10: 8
+            8 => Self::Vega10(Self::to_pptable(bytes)),
+            // Vega20: 11
+            11 => Self::Vega20(Self::to_pptable(bytes)),
+            // Navi10: 12
+            // Navi12: 14
+            // Navi14: 12?
+            12 | 14 => Self::V11_0_0(Self::to_pptable(bytes)),
+            // Navi21: 15
+            // Navi22: 16?
+            // Navi23: 18
+            // Navi24: 19?
+            15 | 16 | 18 | 19 => Self::V11_0_7(Self::to_pptable(bytes)),
+            // Navi31: 20
+            // Navi32, Navi33 (SMU 13.0.7) are not mapped here,
+            // use `decode_with_smu_version` for them
+            20 => Self::V13_0_0(Self::to_pptable(bytes)),
+            _ => Self::Unknown(header),
+        };
+
+        Ok(ppt)
+    }
+
+    pub fn decode_with_smu_version(bytes: &[u8], smu_ver: (u8, u8, u8)) -> Result<Self, PPTableDecodeError> {
+        let Some(header) = Self::get_header(bytes) else { return Err(PPTableDecodeError::SmallerThanHeader) };
+
+        if !Self::check_length(&header, bytes.len()) {
+            return Err(PPTableDecodeError::SmallerThanStructureSizeInHeader);
+        }
+
+        // ref: https://github.com/sibradzic/upp/blob/master/src/upp/decode.py
+        let ppt = match smu_ver {
+            (9, 0, 0) /* Vega10 */ => Self::Vega10(Self::to_pptable(bytes)),
+            /* Arcturus also has MP1 11.0.2, but has the SMU 11 powerplay table */
+            (11, 0, 2) /* Vega20 */ if header.format_revision == 11
+                => Self::Vega20(Self::to_pptable(bytes)),
+            (11, 0, 2) => Self::decode(bytes)?,
+            (11, 0, 0) | /* Navi10 */
+            (11, 0, 5) | /* Navi14 */
+            (11, 0, 9) /* Navi12 */
+                => Self::V11_0_0(Self::to_pptable(bytes)),
+            (11, 0, 7) | /* Navi21 */
+            (11, 0, 11) | /* Navi22 */
+            (11, 0, 12) | /* Navi23 */
+            (11, 0, 13) /* Navi24 */
+                => Self::V11_0_7(Self::to_pptable(bytes)),
+            // The other SMU 13.0.x (Aldebaran, MI300, APUs) have no vendored
+            // `smu_v13_0_*_pptable.h` layout and are decoded as `Unknown`
+            (13, 0, 0) | /* Navi31 */
+            (13, 0, 10) => Self::V13_0_0(Self::to_pptable(bytes)),
+            (13, 0, 7) /* Navi32, Navi33 */ => Self::V13_0_7(Self::to_pptable(bytes)),
+            (14, 0, 2) |
+            (14, 0, 3) => Self::V14_0_2(Self::to_pptable(bytes)),
+            _ => Self::Unknown(header),
+        };
+
+        Ok(ppt)
+    }
+
+    /// The rest is zero-filled if `bytes` is shorter than `T`,
+    /// older revisions of a table can be smaller than the struct
+    fn to_pptable<T>(bytes: &[u8]) -> T {
+        super::read_struct_with_size(bytes, 0, bytes.len()).unwrap()
+    }
+
+    /// Offset of `PPTable_t` (`smcPPTable`/`smc_pptable`) from the start of the table,
+    /// for the variants whose `PPTable_t` layout is not included
+    pub fn smc_pptable_offset(&self) -> Option<usize> {
+        match self {
+            Self::Vega20(_) => Some(offset_of!(ATOM_Vega20_POWERPLAYTABLE, smcPPTable)),
+            Self::V14_0_2(t) => Some(t.pmfw_pptable_start_offset as usize),
+            _ => None,
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Self::Unknown(_) |
+            Self::Invalid => false,
+            _ => true,
+        }
+    }
+}
+
+/// DPM level of the Tonga (Tonga/Fiji/Polaris) and Vega10 PowerPlay tables
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LegacyDpmLevel {
+    /// 10 kHz
+    pub clk: u32,
+    /// Index to the VDDC lookup table
+    pub vdd_index: u8,
+    /// mV, `None` if the index is out of the lookup table.
+    /// Leakage IDs (`0xFF01..`) are returned as they are.
+    pub vddc: Option<u16>,
+}
+
+/// Dependency tables referenced from [PPTable::Tonga] and [PPTable::Vega10]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LegacyDpmTables {
+    /// SCLK (Tonga), GFXCLK (Vega10)
+    pub sclk: Vec<LegacyDpmLevel>,
+    pub mclk: Vec<LegacyDpmLevel>,
+    /// Vega10 only
+    pub socclk: Vec<LegacyDpmLevel>,
+    /// Vega10 only
+    pub dcefclk: Vec<LegacyDpmLevel>,
+    pub vddc_lookup: Vec<u16>,
+    /// W, usTDP (Tonga), usSocketPowerLimit (Vega10)
+    pub power_limit: Option<u16>,
+    /// A
+    pub tdc_limit: Option<u16>,
+    /// A
+    pub edc_limit: Option<u16>,
+    /// C
+    pub software_shutdown_temp: Option<u16>,
+}
+
+impl LegacyDpmTables {
+    // ref: drivers/gpu/drm/amd/pm/powerplay/hwmgr/process_pptables_v1_0.c
+    // ref: drivers/gpu/drm/amd/pm/powerplay/hwmgr/vega10_processpptables.c
+    /// Offsets of the sub-tables are relative to the start of the PowerPlay table
+    pub fn decode(bytes: &[u8]) -> Option<Self> {
+        match PPTable::decode(bytes).ok()? {
+            PPTable::Tonga(ppt) => Some(Self::from_tonga(&ppt, bytes)),
+            PPTable::Vega10(ppt) => Some(Self::from_vega10(&ppt, bytes)),
+            _ => None,
+        }
+    }
+
+    fn from_tonga(ppt: &ATOM_Tonga_POWERPLAYTABLE, bytes: &[u8]) -> Self {
+        let vddc_lookup: Vec<u16> = dependency_table::<ATOM_Tonga_Voltage_Lookup_Record>(
+            bytes,
+            ppt.usVddcLookupTableOffset,
+        ).map(|v| v.iter().map(|r| r.usVdd).collect()).unwrap_or_default();
+        let level = |clk: u32, vdd_index: u8| LegacyDpmLevel {
+            clk,
+            vdd_index,
+            vddc: vddc_lookup.get(vdd_index as usize).copied(),
+        };
+
+        let sclk_offset = ppt.usSclkDependencyTableOffset;
+        let sclk = match bytes.get(sclk_offset as usize) {
+            Some(0) => dependency_table::<ATOM_Tonga_SCLK_Dependency_Record>(bytes, sclk_offset)
+                .map(|v| v.iter().map(|r| level(r.ulSclk, r.ucVddInd)).collect()),
+            Some(_) => dependency_table::<ATOM_Polaris_SCLK_Dependency_Record>(bytes, sclk_offset)
+                .map(|v| v.iter().map(|r| level(r.ulSclk, r.ucVddInd)).collect()),
+            None => None,
+        }.unwrap_or_default();
+        let mclk = dependency_table::<ATOM_Tonga_MCLK_Dependency_Record>(
+            bytes,
+            ppt.usMclkDependencyTableOffset,
+        ).map(|v| v.iter().map(|r| level(r.ulMclk, r.ucVddcInd)).collect()).unwrap_or_default();
+        let power_tune = read_sub_table::<ATOM_Tonga_PowerTune_Table>(bytes, ppt.usPowerTuneTableOffset);
+
+        Self {
+            sclk,
+            mclk,
+            power_limit: power_tune.map(|p| p.usTDP),
+            tdc_limit: power_tune.map(|p| p.usTDC),
+            edc_limit: power_tune.map(|p| p.usEDCLimit),
+            software_shutdown_temp: power_tune.map(|p| p.usSoftwareShutdownTemp),
+            vddc_lookup,
+            ..Default::default()
+        }
+    }
+
+    fn from_vega10(ppt: &ATOM_Vega10_POWERPLAYTABLE, bytes: &[u8]) -> Self {
+        let vddc_lookup: Vec<u16> = dependency_table::<ATOM_Vega10_Voltage_Lookup_Record>(
+            bytes,
+            ppt.usVddcLookupTableOffset,
+        ).map(|v| v.iter().map(|r| r.usVdd).collect()).unwrap_or_default();
+        let level = |clk: u32, vdd_index: u8| LegacyDpmLevel {
+            clk,
+            vdd_index,
+            vddc: vddc_lookup.get(vdd_index as usize).copied(),
+        };
+        let clk_table = |offset: u16| -> Vec<LegacyDpmLevel> {
+            dependency_table::<ATOM_Vega10_CLK_Dependency_Record>(bytes, offset)
+                .map(|v| v.iter().map(|r| level(r.ulClk, r.ucVddInd)).collect())
+                .unwrap_or_default()
+        };
+
+        let gfxclk_offset = ppt.usGfxclkDependencyTableOffset;
+        let sclk = match bytes.get(gfxclk_offset as usize) {
+            Some(0) => dependency_table::<ATOM_Vega10_GFXCLK_Dependency_Record>(bytes, gfxclk_offset)
+                .map(|v| v.iter().map(|r| level(r.ulClk, r.ucVddInd)).collect()),
+            Some(1) => dependency_table::<ATOM_Vega10_GFXCLK_Dependency_Record_V2>(bytes, gfxclk_offset)
+                .map(|v| v.iter().map(|r| level(r.ulClk, r.ucVddInd)).collect()),
+            _ => None,
+        }.unwrap_or_default();
+        let mclk = dependency_table::<ATOM_Vega10_MCLK_Dependency_Record>(
+            bytes,
+            ppt.usMclkDependencyTableOffset,
+        ).map(|v| v.iter().map(|r| level(r.ulMemClk, r.ucVddInd)).collect()).unwrap_or_default();
+        let power_tune = read_sub_table::<ATOM_Vega10_PowerTune_Table>(bytes, ppt.usPowerTuneTableOffset);
+
+        Self {
+            sclk,
+            mclk,
+            socclk: clk_table(ppt.usSocclkDependencyTableOffset),
+            dcefclk: clk_table(ppt.usDcefclkDependencyTableOffset),
+            power_limit: power_tune.map(|p| p.usSocketPowerLimit),
+            tdc_limit: power_tune.map(|p| p.usTdcLimit),
+            edc_limit: power_tune.map(|p| p.usEdcLimit),
+            software_shutdown_temp: power_tune.map(|p| p.usSoftwareShutdownTemp),
+            vddc_lookup,
+        }
+    }
+}
+
+fn read_sub_table<T>(bytes: &[u8], offset: u16) -> Option<T> {
+    if offset == 0 { return None }
+
+    read_struct(bytes, offset as usize)
+}
+
+/// `{ UCHAR ucRevId; UCHAR ucNumEntries; T entries[]; }`
+fn dependency_table<T>(bytes: &[u8], offset: u16) -> Option<Vec<T>> {
+    if offset == 0 { return None }
+
+    let offset = offset as usize;
+    let num_entries = *bytes.get(offset + 1)? as usize;
+
+    (0..num_entries)
+        .map(|i| read_struct::<T>(bytes, offset + 2 + i * size_of::<T>()))
+        .collect()
+}
+
+#[test]
+fn test_smc_pptable_offset() {
+    let size = size_of::<ATOM_Vega20_POWERPLAYTABLE>();
+    /* driver portion + smcPPTable */
+    let mut bytes = vec![0u8; size + 16];
+    let len = bytes.len() as u16;
+    bytes[0..2].copy_from_slice(&len.to_le_bytes());
+    bytes[2] = 11;
+
+    let ppt = PPTable::decode(&bytes).unwrap();
+
+    assert!(matches!(ppt, PPTable::Vega20(_)));
+    assert_eq!(ppt.smc_pptable_offset(), Some(size));
+    assert!(matches!(PPTable::decode_with_smu_version(&bytes, (11, 0, 2)), Ok(PPTable::Vega20(_))));
+
+    /* Arcturus (MP1 11.0.2) */
+    bytes[2] = 12;
+
+    let ppt = PPTable::decode_with_smu_version(&bytes, (11, 0, 2)).unwrap();
+
+    assert!(matches!(ppt, PPTable::V11_0_0(_)));
+    assert_eq!(ppt.smc_pptable_offset(), None);
+
+    let size = size_of::<smu_14_0_2_powerplay_table>();
+    let mut bytes = vec![0u8; size + 16];
+    let len = bytes.len() as u16;
+    bytes[0..2].copy_from_slice(&len.to_le_bytes());
+    bytes[2] = 3;
+    let pos = offset_of!(smu_14_0_2_powerplay_table, pmfw_pptable_start_offset);
+    bytes[pos..pos+2].copy_from_slice(&(size as u16).to_le_bytes());
+
+    let ppt = PPTable::decode(&bytes).unwrap();
+
+    assert!(matches!(ppt, PPTable::V14_0_2(_)));
+    assert_eq!(ppt.smc_pptable_offset(), Some(size));
+
+    let ppt = PPTable::decode_with_smu_version(&bytes, (14, 0, 3)).unwrap();
+
+    assert_eq!(ppt.smc_pptable_offset(), Some(size));
+    assert_eq!(PPTable::Tonga(PPTable::to_pptable(&bytes)).smc_pptable_offset(), None);
+}
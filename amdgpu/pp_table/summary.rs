@@ -0,0 +1,387 @@
+use crate::AMDGPU::DpmClockType;
+use super::{PPTable, PPTableOdSetting, LegacyDpmTables};
+use crate::bindings::ppt::{
+    smu_v11_0_0_ppt::{
+        SMU_11_0_ODSETTING_ID_SMU_11_0_ODSETTING_GFXCLKFMAX,
+        SMU_11_0_ODSETTING_ID_SMU_11_0_ODSETTING_GFXCLKFMIN,
+        SMU_11_0_ODSETTING_ID_SMU_11_0_ODSETTING_UCLKFMAX,
+        SMU_11_0_ODSETTING_ID_SMU_11_0_ODSETTING_POWERPERCENTAGE,
+    },
+    smu_v11_0_7_ppt::{
+        SMU_11_0_7_ODSETTING_ID_SMU_11_0_7_ODSETTING_GFXCLKFMAX,
+        SMU_11_0_7_ODSETTING_ID_SMU_11_0_7_ODSETTING_GFXCLKFMIN,
+        SMU_11_0_7_ODSETTING_ID_SMU_11_0_7_ODSETTING_UCLKFMIN,
+        SMU_11_0_7_ODSETTING_ID_SMU_11_0_7_ODSETTING_UCLKFMAX,
+        SMU_11_0_7_ODSETTING_ID_SMU_11_0_7_ODSETTING_POWERPERCENTAGE,
+        TEMP_e_TEMP_EDGE as SMU_11_TEMP_EDGE,
+        TEMP_e_TEMP_HOTSPOT as SMU_11_TEMP_HOTSPOT,
+        TEMP_e_TEMP_MEM as SMU_11_TEMP_MEM,
+    },
+    smu_v13_0_0_ppt::{
+        SMU_13_0_0_ODSETTING_ID_SMU_13_0_0_ODSETTING_GFXCLKFMAX,
+        SMU_13_0_0_ODSETTING_ID_SMU_13_0_0_ODSETTING_GFXCLKFMIN,
+        SMU_13_0_0_ODSETTING_ID_SMU_13_0_0_ODSETTING_UCLKFMIN,
+        SMU_13_0_0_ODSETTING_ID_SMU_13_0_0_ODSETTING_UCLKFMAX,
+        SMU_13_0_0_ODSETTING_ID_SMU_13_0_0_ODSETTING_POWERPERCENTAGE,
+        TEMP_e_TEMP_EDGE,
+        TEMP_e_TEMP_HOTSPOT,
+        TEMP_e_TEMP_MEM,
+    },
+};
+
+/* SMU 13.0.7 and 14.0.2 use the same IDs as SMU 13.0.0, Vega20 the same IDs as SMU 11.0.0 */
+pub(super) const SMU_11_0_OD_IDS: OdIds = OdIds {
+    gfxclk_fmin: Some(SMU_11_0_ODSETTING_ID_SMU_11_0_ODSETTING_GFXCLKFMIN as usize),
+    gfxclk_fmax: Some(SMU_11_0_ODSETTING_ID_SMU_11_0_ODSETTING_GFXCLKFMAX as usize),
+    uclk_fmin: None,
+    uclk_fmax: Some(SMU_11_0_ODSETTING_ID_SMU_11_0_ODSETTING_UCLKFMAX as usize),
+    power_percentage: Some(SMU_11_0_ODSETTING_ID_SMU_11_0_ODSETTING_POWERPERCENTAGE as usize),
+};
+pub(super) const SMU_11_0_7_OD_IDS: OdIds = OdIds {
+    gfxclk_fmin: Some(SMU_11_0_7_ODSETTING_ID_SMU_11_0_7_ODSETTING_GFXCLKFMIN as usize),
+    gfxclk_fmax: Some(SMU_11_0_7_ODSETTING_ID_SMU_11_0_7_ODSETTING_GFXCLKFMAX as usize),
+    uclk_fmin: Some(SMU_11_0_7_ODSETTING_ID_SMU_11_0_7_ODSETTING_UCLKFMIN as usize),
+    uclk_fmax: Some(SMU_11_0_7_ODSETTING_ID_SMU_11_0_7_ODSETTING_UCLKFMAX as usize),
+    power_percentage: Some(SMU_11_0_7_ODSETTING_ID_SMU_11_0_7_ODSETTING_POWERPERCENTAGE as usize),
+};
+pub(super) const SMU_13_0_0_OD_IDS: OdIds = OdIds {
+    gfxclk_fmin: Some(SMU_13_0_0_ODSETTING_ID_SMU_13_0_0_ODSETTING_GFXCLKFMIN as usize),
+    gfxclk_fmax: Some(SMU_13_0_0_ODSETTING_ID_SMU_13_0_0_ODSETTING_GFXCLKFMAX as usize),
+    uclk_fmin: Some(SMU_13_0_0_ODSETTING_ID_SMU_13_0_0_ODSETTING_UCLKFMIN as usize),
+    uclk_fmax: Some(SMU_13_0_0_ODSETTING_ID_SMU_13_0_0_ODSETTING_UCLKFMAX as usize),
+    power_percentage: Some(SMU_13_0_0_ODSETTING_ID_SMU_13_0_0_ODSETTING_POWERPERCENTAGE as usize),
+};
+
+pub(super) struct OdIds {
+    gfxclk_fmin: Option<usize>,
+    gfxclk_fmax: Option<usize>,
+    uclk_fmin: Option<usize>,
+    uclk_fmax: Option<usize>,
+    power_percentage: Option<usize>,
+}
+
+impl OdIds {
+    pub(super) fn get(&self, setting: PPTableOdSetting) -> Option<usize> {
+        match setting {
+            PPTableOdSetting::GfxclkFmin => self.gfxclk_fmin,
+            PPTableOdSetting::GfxclkFmax => self.gfxclk_fmax,
+            PPTableOdSetting::UclkFmin => self.uclk_fmin,
+            PPTableOdSetting::UclkFmax => self.uclk_fmax,
+            PPTableOdSetting::PowerPercentage => self.power_percentage,
+        }
+    }
+
+    fn ranges<T: Copy + Into<i64>>(&self, min: &[T], max: &[T]) -> PPTableOdRanges {
+        let range = |id: Option<usize>| -> Option<OdRange> {
+            let id = id?;
+
+            Some(OdRange { min: (*min.get(id)?).into(), max: (*max.get(id)?).into() })
+        };
+
+        PPTableOdRanges {
+            gfxclk_fmin: range(self.gfxclk_fmin),
+            gfxclk_fmax: range(self.gfxclk_fmax),
+            uclk_fmin: range(self.uclk_fmin),
+            uclk_fmax: range(self.uclk_fmax),
+            power_percentage: range(self.power_percentage),
+        }
+    }
+}
+
+/// Range of an overdrive setting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OdRange {
+    pub min: i64,
+    pub max: i64,
+}
+
+/// MHz for clocks, % for the power limit
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PPTableOdRanges {
+    pub gfxclk_fmin: Option<OdRange>,
+    pub gfxclk_fmax: Option<OdRange>,
+    pub uclk_fmin: Option<OdRange>,
+    pub uclk_fmax: Option<OdRange>,
+    pub power_percentage: Option<OdRange>,
+}
+
+/// Default fan control parameters
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PPTableFanDefaults {
+    /// C
+    pub target_temperature: Option<u16>,
+    /// C
+    pub start_temperature: Option<u16>,
+    /// C
+    pub stop_temperature: Option<u16>,
+    /// PWM, 0 - 255
+    pub pwm_min: Option<u16>,
+    pub max_rpm: Option<u16>,
+    pub acoustic_limit_rpm: Option<u16>,
+    pub zero_rpm_enable: Option<bool>,
+}
+
+/// Common values of the PowerPlay tables across SMU generations
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PPTableSummary {
+    /// W, PPT0 AC
+    pub power_limit_ac: Option<u16>,
+    /// W, PPT0 DC
+    pub power_limit_dc: Option<u16>,
+    /// W
+    pub small_power_limit1: Option<u16>,
+    /// W
+    pub small_power_limit2: Option<u16>,
+    /// W
+    pub boost_power_limit: Option<u16>,
+    /// C
+    pub software_shutdown_temp: Option<u16>,
+    /// C
+    pub temp_limit_edge: Option<u16>,
+    /// C
+    pub temp_limit_hotspot: Option<u16>,
+    /// C
+    pub temp_limit_mem: Option<u16>,
+    /// A
+    pub tdc_limit_gfx: Option<u16>,
+    /// A
+    pub tdc_limit_soc: Option<u16>,
+    /// A, only in the Tonga/Vega10 PowerTune tables
+    pub edc_limit: Option<u16>,
+    pub od: PPTableOdRanges,
+    pub fan: PPTableFanDefaults,
+    /// MHz, zero levels and repeated levels are removed
+    pub dpm_freq_tables: Vec<(DpmClockType, Vec<u32>)>,
+}
+
+impl PPTableSummary {
+    /// Unlike `PPTable::summary`, the sub-tables of Tonga/Vega10 tables are also read
+    pub fn decode(bytes: &[u8]) -> Option<Self> {
+        let ppt = PPTable::decode(bytes).ok()?;
+        let mut summary = ppt.summary()?;
+
+        if let Some(dpm) = LegacyDpmTables::decode(bytes) {
+            /* 10 kHz -> MHz */
+            let to_mhz = |levels: &[super::LegacyDpmLevel]| -> Vec<u32> {
+                freq_levels(levels.iter().map(|l| l.clk / 100))
+            };
+
+            summary.power_limit_ac = dpm.power_limit;
+            summary.tdc_limit_gfx = dpm.tdc_limit;
+            summary.edc_limit = dpm.edc_limit;
+            summary.software_shutdown_temp = summary.software_shutdown_temp.or(dpm.software_shutdown_temp);
+            summary.dpm_freq_tables = [
+                (DpmClockType::SCLK, to_mhz(&dpm.sclk)),
+                (DpmClockType::MCLK, to_mhz(&dpm.mclk)),
+                (DpmClockType::SOCCLK, to_mhz(&dpm.socclk)),
+            ].into_iter().filter(|(_, v)| !v.is_empty()).collect();
+        }
+
+        Some(summary)
+    }
+}
+
+/// Drops zero levels and consecutive duplicates (unused levels repeat the last one)
+fn freq_levels<T: Into<u32>, I: Iterator<Item = T>>(levels: I) -> Vec<u32> {
+    let mut v: Vec<u32> = levels.map(|l| l.into()).filter(|l| *l != 0).collect();
+    v.dedup();
+
+    v
+}
+
+/* SMU 13.0.0 and 13.0.7 have the same SkuTable_t layout, but different types */
+macro_rules! smu_13_0_x_summary {
+    ($t:expr, $sku:expr, $od_min:expr, $od_max:expr) => {{
+        let sku = $sku;
+        /* fan control parameters are per temperature sensor, use the hotspot one as the driver does */
+        let hotspot = TEMP_e_TEMP_HOTSPOT as usize;
+
+        PPTableSummary {
+            power_limit_ac: Some(sku.SocketPowerLimitAc[0]),
+            power_limit_dc: Some(sku.SocketPowerLimitDc[0]),
+            small_power_limit1: Some($t.small_power_limit1),
+            small_power_limit2: Some($t.small_power_limit2),
+            boost_power_limit: Some($t.boost_power_limit),
+            software_shutdown_temp: Some($t.software_shutdown_temp),
+            temp_limit_edge: Some(sku.TemperatureLimit[TEMP_e_TEMP_EDGE as usize]),
+            temp_limit_hotspot: Some(sku.TemperatureLimit[hotspot]),
+            temp_limit_mem: Some(sku.TemperatureLimit[TEMP_e_TEMP_MEM as usize]),
+            tdc_limit_gfx: Some(sku.VrTdcLimit[0]),
+            tdc_limit_soc: Some(sku.VrTdcLimit[1]),
+            edc_limit: None,
+            od: SMU_13_0_0_OD_IDS.ranges(&$od_min, &$od_max),
+            fan: PPTableFanDefaults {
+                target_temperature: Some(sku.FanTargetTemperature[hotspot]),
+                start_temperature: Some(sku.FanStartTemp[hotspot]),
+                stop_temperature: Some(sku.FanStopTemp[hotspot]),
+                pwm_min: Some(sku.FanPwmMin),
+                max_rpm: Some(sku.FanMaximumRpm),
+                acoustic_limit_rpm: Some(sku.AcousticLimitRpmThreshold),
+                zero_rpm_enable: Some(sku.FanZeroRpmEnable != 0),
+            },
+            dpm_freq_tables: vec![
+                (DpmClockType::SCLK, freq_levels(sku.FreqTableGfx.into_iter())),
+                (DpmClockType::MCLK, freq_levels(sku.FreqTableUclk.into_iter())),
+                (DpmClockType::FCLK, freq_levels(sku.FreqTableFclk.into_iter())),
+                (DpmClockType::SOCCLK, freq_levels(sku.FreqTableSocclk.into_iter())),
+                (DpmClockType::VCLK, freq_levels(sku.FreqTableVclk.into_iter())),
+                (DpmClockType::DCLK, freq_levels(sku.FreqTableDclk.into_iter())),
+            ],
+        }
+    }};
+}
+
+impl PPTable {
+    /// `None` for [PPTable::Unknown] and [PPTable::Invalid]
+    pub fn summary(&self) -> Option<PPTableSummary> {
+        let s = match self {
+            Self::V11_0_0(t) => {
+                let smc = { t.smc_pptable };
+                let (od_min, od_max) = ({ t.overdrive_table.min }, { t.overdrive_table.max });
+
+                PPTableSummary {
+                    power_limit_ac: Some(smc.SocketPowerLimitAc[0]),
+                    power_limit_dc: Some(smc.SocketPowerLimitDc[0]),
+                    small_power_limit1: Some(t.small_power_limit1),
+                    small_power_limit2: Some(t.small_power_limit2),
+                    boost_power_limit: Some(t.boost_power_limit),
+                    software_shutdown_temp: Some(t.software_shutdown_temp),
+                    temp_limit_edge: Some(smc.TedgeLimit),
+                    temp_limit_hotspot: Some(smc.ThotspotLimit),
+                    temp_limit_mem: Some(smc.TmemLimit),
+                    tdc_limit_gfx: Some(smc.TdcLimitGfx),
+                    tdc_limit_soc: Some(smc.TdcLimitSoc),
+                    edc_limit: None,
+                    od: SMU_11_0_OD_IDS.ranges(&od_min, &od_max),
+                    fan: PPTableFanDefaults {
+                        target_temperature: Some(smc.FanTargetTemperature),
+                        start_temperature: Some(smc.FanStartTemp),
+                        stop_temperature: Some(smc.FanStopTemp),
+                        pwm_min: Some(smc.FanPwmMin),
+                        max_rpm: Some(smc.FanMaximumRpm),
+                        acoustic_limit_rpm: Some(smc.FanAcousticLimitRpm),
+                        zero_rpm_enable: Some(smc.FanZeroRpmEnable != 0),
+                    },
+                    dpm_freq_tables: vec![
+                        (DpmClockType::SCLK, freq_levels({ smc.FreqTableGfx }.into_iter())),
+                        (DpmClockType::MCLK, freq_levels({ smc.FreqTableUclk }.into_iter())),
+                        (DpmClockType::SOCCLK, freq_levels({ smc.FreqTableSocclk }.into_iter())),
+                        (DpmClockType::VCLK, freq_levels({ smc.FreqTableVclk }.into_iter())),
+                        (DpmClockType::DCLK, freq_levels({ smc.FreqTableDclk }.into_iter())),
+                    ],
+                }
+            },
+            Self::V11_0_7(t) => {
+                let smc = { t.smc_pptable };
+                let (od_min, od_max) = ({ t.overdrive_table.min }, { t.overdrive_table.max });
+
+                PPTableSummary {
+                    power_limit_ac: Some(smc.SocketPowerLimitAc[0]),
+                    power_limit_dc: Some(smc.SocketPowerLimitDc[0]),
+                    small_power_limit1: Some(t.small_power_limit1),
+                    small_power_limit2: Some(t.small_power_limit2),
+                    boost_power_limit: Some(t.boost_power_limit),
+                    software_shutdown_temp: Some(t.software_shutdown_temp),
+                    temp_limit_edge: Some(smc.TemperatureLimit[SMU_11_TEMP_EDGE as usize]),
+                    temp_limit_hotspot: Some(smc.TemperatureLimit[SMU_11_TEMP_HOTSPOT as usize]),
+                    temp_limit_mem: Some(smc.TemperatureLimit[SMU_11_TEMP_MEM as usize]),
+                    tdc_limit_gfx: Some(smc.TdcLimit[0]),
+                    tdc_limit_soc: Some(smc.TdcLimit[1]),
+                    edc_limit: None,
+                    od: SMU_11_0_7_OD_IDS.ranges(&od_min, &od_max),
+                    fan: PPTableFanDefaults {
+                        target_temperature: Some(smc.FanTargetTemperature),
+                        start_temperature: Some(smc.FanStartTemp),
+                        stop_temperature: Some(smc.FanStopTemp),
+                        pwm_min: Some(smc.FanPwmMin),
+                        max_rpm: Some(smc.FanMaximumRpm),
+                        acoustic_limit_rpm: Some(smc.FanAcousticLimitRpm),
+                        zero_rpm_enable: Some(smc.FanZeroRpmEnable != 0),
+                    },
+                    dpm_freq_tables: vec![
+                        (DpmClockType::SCLK, freq_levels({ smc.FreqTableGfx }.into_iter())),
+                        (DpmClockType::MCLK, freq_levels({ smc.FreqTableUclk }.into_iter())),
+                        (DpmClockType::FCLK, freq_levels({ smc.FreqTableFclk }.into_iter())),
+                        (DpmClockType::SOCCLK, freq_levels({ smc.FreqTableSocclk }.into_iter())),
+                        (DpmClockType::VCLK, freq_levels({ smc.FreqTableVclk }.into_iter())),
+                        (DpmClockType::DCLK, freq_levels({ smc.FreqTableDclk }.into_iter())),
+                    ],
+                }
+            },
+            Self::V13_0_0(t) => {
+                let sku = { t.smc_pptable.SkuTable };
+                let (od_min, od_max) = ({ t.overdrive_table.min }, { t.overdrive_table.max });
+
+                smu_13_0_x_summary!(t, sku, od_min, od_max)
+            },
+            Self::V13_0_7(t) => {
+                let sku = { t.smc_pptable.SkuTable };
+                let (od_min, od_max) = ({ t.overdrive_table.min }, { t.overdrive_table.max });
+
+                smu_13_0_x_summary!(t, sku, od_min, od_max)
+            },
+            Self::V14_0_2(t) => {
+                let (od_min, od_max) = ({ t.overdrive_table.min }, { t.overdrive_table.max });
+
+                PPTableSummary {
+                    small_power_limit1: Some(t.small_power_limit1),
+                    small_power_limit2: Some(t.small_power_limit2),
+                    boost_power_limit: Some(t.boost_power_limit),
+                    software_shutdown_temp: Some(t.software_shutdown_temp),
+                    od: SMU_13_0_0_OD_IDS.ranges(&od_min, &od_max),
+                    ..Default::default()
+                }
+            },
+            Self::Vega20(t) => {
+                let od = { t.OverDrive8Table };
+                let (od_min, od_max) = ({ od.ODSettingsMin }, { od.ODSettingsMax });
+
+                PPTableSummary {
+                    small_power_limit1: Some(t.usSmallPowerLimit1),
+                    small_power_limit2: Some(t.usSmallPowerLimit2),
+                    boost_power_limit: Some(t.usBoostPowerLimit),
+                    software_shutdown_temp: Some(t.usSoftwareShutdownTemp),
+                    od: SMU_11_0_OD_IDS.ranges(&od_min, &od_max),
+                    ..Default::default()
+                }
+            },
+            /* Power and clock limits are in the sub-tables, see `PPTableSummary::decode` */
+            Self::Tonga(t) => PPTableSummary {
+                od: legacy_od_ranges(t.ulMaxODEngineClock, t.ulMaxODMemoryClock, t.usPowerControlLimit),
+                ..Default::default()
+            },
+            Self::Vega10(t) => PPTableSummary {
+                od: legacy_od_ranges(t.ulMaxODEngineClock, t.ulMaxODMemoryClock, t.usPowerControlLimit),
+                ..Default::default()
+            },
+            Self::Unknown(_) |
+            Self::Invalid => return None,
+        };
+
+        Some(s)
+    }
+}
+
+/// Only the maximums are in the table, 10 kHz
+fn legacy_od_ranges(max_sclk: u32, max_mclk: u32, power_control_limit: u16) -> PPTableOdRanges {
+    let max_only = |max: i64| (max != 0).then_some(OdRange { min: 0, max });
+
+    PPTableOdRanges {
+        gfxclk_fmax: max_only(max_sclk as i64 / 100),
+        uclk_fmax: max_only(max_mclk as i64 / 100),
+        power_percentage: (power_control_limit != 0)
+            .then_some(OdRange { min: -(power_control_limit as i64), max: power_control_limit as i64 }),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_summary_helpers() {
+    assert_eq!(freq_levels([0u16, 500, 1000, 2000, 2000, 2000, 0, 0].into_iter()), vec![500, 1000, 2000]);
+
+    let od = legacy_od_ranges(200_000, 0, 50);
+
+    assert_eq!(od.gfxclk_fmax, Some(OdRange { min: 0, max: 2000 }));
+    assert_eq!(od.uclk_fmax, None);
+    assert_eq!(od.power_percentage, Some(OdRange { min: -50, max: 50 }));
+}
@@ -0,0 +1,192 @@
+use crate::AMDGPU::{DeviceHandle, RasBlock, RasErrorCount};
+use crate::PCI;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+/* ref: drivers/gpu/drm/amd/amdgpu/amdgpu_ras.c, `amdgpu_ras_debugfs_ctrl_parse_data` */
+
+impl DeviceHandle {
+    /// Write the command to `ras/ras_ctrl` (debugfs), and then read back the [RasErrorCount].  
+    /// Requires root privileges.
+    pub fn ras_ctrl(&self, cmd: &RasCtrlCommand) -> io::Result<RasErrorCount> {
+        let pci_bus = self
+            .get_pci_bus_info()
+            .map_err(|_| io::Error::other("Failed get_pci_bus_info"))?;
+
+        cmd.execute(&pci_bus)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RasCtrlOp {
+    Disable,
+    Enable,
+    Inject,
+}
+
+impl RasCtrlOp {
+    pub const fn to_arg(&self) -> &str {
+        match self {
+            Self::Disable => "disable",
+            Self::Enable => "enable",
+            Self::Inject => "inject",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RasErrorType {
+    /// Multi Uncorrectable
+    UE,
+    /// Single Correctable
+    CE,
+    Poison,
+}
+
+impl RasErrorType {
+    pub const fn to_arg(&self) -> &str {
+        match self {
+            Self::UE => "ue",
+            Self::CE => "ce",
+            Self::Poison => "poison",
+        }
+    }
+}
+
+impl fmt::Display for RasErrorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Command for `ras/ras_ctrl` (debugfs)
+///
+/// ```
+/// use libdrm_amdgpu_sys::AMDGPU::{RasBlock, RasCtrlCommand, RasErrorType};
+///
+/// let cmd = RasCtrlCommand::inject(RasBlock::UMC, RasErrorType::UE)
+///     .address(0x1000)
+///     .value(0x1);
+///
+/// assert_eq!(cmd.dry_run(), "inject umc ue 0x0 0x1000 0x1");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RasCtrlCommand {
+    pub op: RasCtrlOp,
+    pub block: RasBlock,
+    pub error_type: Option<RasErrorType>,
+    pub sub_block: u32,
+    pub address: u64,
+    pub value: u64,
+    pub instance_mask: Option<u32>,
+}
+
+impl RasCtrlCommand {
+    fn new(op: RasCtrlOp, block: RasBlock, error_type: Option<RasErrorType>) -> Self {
+        Self {
+            op,
+            block,
+            error_type,
+            sub_block: 0,
+            address: 0,
+            value: 0,
+            instance_mask: None,
+        }
+    }
+
+    pub fn disable(block: RasBlock) -> Self {
+        Self::new(RasCtrlOp::Disable, block, None)
+    }
+
+    pub fn enable(block: RasBlock, error_type: RasErrorType) -> Self {
+        Self::new(RasCtrlOp::Enable, block, Some(error_type))
+    }
+
+    pub fn inject(block: RasBlock, error_type: RasErrorType) -> Self {
+        Self::new(RasCtrlOp::Inject, block, Some(error_type))
+    }
+
+    pub fn sub_block(mut self, sub_block: u32) -> Self {
+        self.sub_block = sub_block;
+        self
+    }
+
+    pub fn address(mut self, address: u64) -> Self {
+        self.address = address;
+        self
+    }
+
+    pub fn value(mut self, value: u64) -> Self {
+        self.value = value;
+        self
+    }
+
+    pub fn instance_mask(mut self, instance_mask: u32) -> Self {
+        self.instance_mask = Some(instance_mask);
+        self
+    }
+
+    /// Returns the command string without writing it to `ras_ctrl`
+    pub fn dry_run(&self) -> String {
+        let block = self.block.to_sysfs_name_prefix();
+        let err = self.error_type.as_ref().map(|e| e.to_arg()).unwrap_or("");
+
+        match self.op {
+            RasCtrlOp::Disable => format!("disable {block}"),
+            RasCtrlOp::Enable => format!("enable {block} {err}"),
+            RasCtrlOp::Inject => {
+                let mut s = format!(
+                    "inject {block} {err} {:#x} {:#x} {:#x}",
+                    self.sub_block,
+                    self.address,
+                    self.value,
+                );
+
+                if let Some(mask) = self.instance_mask {
+                    s.push_str(&format!(" {mask:#x}"));
+                }
+
+                s
+            },
+        }
+    }
+
+    /// `/sys/kernel/debug/dri/#/`
+    pub fn write_to_debugfs<P: Into<PathBuf>>(&self, debug_dri_path: P) -> io::Result<()> {
+        let path = debug_dri_path.into().join("ras/ras_ctrl");
+
+        std::fs::write(path, self.dry_run())
+    }
+
+    /// Write the command, and then read back the [RasErrorCount] of the block.  
+    /// Requires root privileges.
+    pub fn execute(&self, pci_bus: &PCI::BUS_INFO) -> io::Result<RasErrorCount> {
+        self.write_to_debugfs(pci_bus.get_debug_dri_path()?)?;
+
+        RasErrorCount::get_from_sysfs_with_ras_block(pci_bus.get_sysfs_path(), self.block)
+    }
+}
+
+impl fmt::Display for RasCtrlCommand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.dry_run())
+    }
+}
+
+#[test]
+fn test_ras_ctrl_command() {
+    assert_eq!(RasCtrlCommand::disable(RasBlock::GFX).dry_run(), "disable gfx");
+    assert_eq!(
+        RasCtrlCommand::enable(RasBlock::PCIE, RasErrorType::CE).dry_run(),
+        "enable pcie_bif ce",
+    );
+    assert_eq!(
+        RasCtrlCommand::inject(RasBlock::XGMI, RasErrorType::Poison)
+            .sub_block(2)
+            .address(0xdead)
+            .instance_mask(0x1)
+            .dry_run(),
+        "inject xgmi_wafl poison 0x2 0xdead 0x0 0x1",
+    );
+}
@@ -47,7 +47,7 @@ use crate::bindings::{
     AMDGPU_INFO_RAS_ENABLED_FUSE,
 };
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u32)]
 pub enum RasBlock {
     UMC = AMDGPU_INFO_RAS_ENABLED_UMC,
@@ -67,6 +67,23 @@ pub enum RasBlock {
 }
 
 impl RasBlock {
+    pub const ALL: [Self; 14] = [
+        Self::UMC,
+        Self::SDMA,
+        Self::GFX,
+        Self::MMHUB,
+        Self::ATHUB,
+        Self::PCIE,
+        Self::HDP,
+        Self::XGMI,
+        Self::DF,
+        Self::SMN,
+        Self::SEM,
+        Self::MP0,
+        Self::MP1,
+        Self::FUSE,
+    ];
+
     /// ref: drivers/gpu/drm/amd/amdgpu/amdgpu_ras.c
     pub fn to_sysfs_name_prefix(&self) -> &str {
         match self {
@@ -94,7 +111,7 @@ impl fmt::Display for RasBlock {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RasErrorCount {
     pub uncorrected: u64,
     pub corrected: u64,
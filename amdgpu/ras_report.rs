@@ -0,0 +1,157 @@
+use crate::AMDGPU::{DeviceHandle, RasBlock, RasEnabledFeatures, RasErrorCount};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/* ref: drivers/gpu/drm/amd/amdgpu/amdgpu_ras.c */
+/* ref: drivers/gpu/drm/amd/amdgpu/amdgpu_ras_eeprom.c */
+
+impl DeviceHandle {
+    /// The EEPROM table record count requires debugfs (root privileges),
+    /// otherwise it will be `None`.
+    pub fn get_ras_report(&self) -> Result<RasReport, i32> {
+        let enabled_features = self.ras_enabled_features()?;
+        let pci_bus = self.get_pci_bus_info()?;
+        let mut report = RasReport::get_from_sysfs(pci_bus.get_sysfs_path(), enabled_features);
+
+        if let Ok(debug_dri_path) = pci_bus.get_debug_dri_path() {
+            report.eeprom_record_count = RasReport::get_eeprom_record_count_from_debugfs(debug_dri_path);
+        }
+
+        Ok(report)
+    }
+}
+
+/// Snapshot of RAS features, error counts for every supported [RasBlock] and retired pages
+#[derive(Debug, Clone)]
+pub struct RasReport {
+    /// from `AMDGPU_INFO_RAS_ENABLED_FEATURES`
+    pub enabled_features: RasEnabledFeatures,
+    /// from `ras/features`
+    pub sysfs_features: Option<RasEnabledFeatures>,
+    /// from `ras/version`, EEPROM table version
+    pub version: Option<u32>,
+    /// from `ras/schema`
+    pub schema: Option<u32>,
+    pub error_counts: Vec<(RasBlock, RasErrorCount)>,
+    /// from `ras/gpu_vram_bad_pages`
+    pub bad_pages: Vec<RasBadPage>,
+    /// from `ras/ras_eeprom_table` (debugfs)
+    pub eeprom_record_count: Option<u32>,
+}
+
+impl RasReport {
+    pub fn get_from_sysfs<P: Into<PathBuf>>(
+        sysfs_path: P,
+        enabled_features: RasEnabledFeatures,
+    ) -> Self {
+        let sysfs_path = sysfs_path.into();
+        let ras_path = sysfs_path.join("ras");
+
+        let [sysfs_features, version, schema] = ["features", "version", "schema"]
+            .map(|name| Self::parse_hex_value_file(&ras_path.join(name)));
+        let error_counts = RasBlock::ALL
+            .into_iter()
+            .filter(|block| enabled_features.is_supported(*block))
+            .filter_map(|block| {
+                let cnt = RasErrorCount::get_from_sysfs_with_ras_block(&sysfs_path, block).ok()?;
+
+                Some((block, cnt))
+            })
+            .collect();
+        let bad_pages = RasBadPage::get_all_from_sysfs(&sysfs_path);
+
+        Self {
+            enabled_features,
+            sysfs_features: sysfs_features.map(|v| RasEnabledFeatures::new(v as u64)),
+            version,
+            schema,
+            error_counts,
+            bad_pages,
+            eeprom_record_count: None,
+        }
+    }
+
+    /// `/sys/kernel/debug/dri/#/ras/ras_eeprom_table`
+    pub fn get_eeprom_record_count_from_debugfs<P: Into<PathBuf>>(debug_dri_path: P) -> Option<u32> {
+        let s = std::fs::read_to_string(debug_dri_path.into().join("ras/ras_eeprom_table")).ok()?;
+        let mut lines = s.lines().skip_while(|l| !l.trim_start().starts_with("Index"));
+
+        /* skip the record header */
+        lines.next()?;
+
+        Some(lines.filter(|l| !l.trim().is_empty()).count() as u32)
+    }
+
+    /// "feature mask: 0x3fbf", "table version: 0x20000", "schema: 0x5"
+    fn parse_hex_value_file(path: &Path) -> Option<u32> {
+        let s = std::fs::read_to_string(path).ok()?;
+        let (_, v) = s.rsplit_once(": ")?;
+
+        u32::from_str_radix(v.trim().trim_start_matches("0x"), 16).ok()
+    }
+
+    pub fn total_uncorrected(&self) -> u64 {
+        self.error_counts.iter().map(|(_, cnt)| cnt.uncorrected).sum()
+    }
+
+    pub fn total_corrected(&self) -> u64 {
+        self.error_counts.iter().map(|(_, cnt)| cnt.corrected).sum()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RasBadPage {
+    /// Retired page frame number
+    pub page: u64,
+    pub size: u64,
+    pub flag: RasBadPageFlag,
+}
+
+impl RasBadPage {
+    pub fn get_all_from_sysfs<P: Into<PathBuf>>(sysfs_path: P) -> Vec<Self> {
+        let path = sysfs_path.into().join("ras/gpu_vram_bad_pages");
+        let Ok(s) = std::fs::read_to_string(path) else { return Vec::new() };
+
+        s.lines().filter_map(Self::parse_line).collect()
+    }
+
+    /// "0x00000001 : 0x00001000 : R"
+    pub fn parse_line(s: &str) -> Option<Self> {
+        let mut split = s.split(" : ");
+        let [page, size] = [split.next()?, split.next()?].map(|v| {
+            u64::from_str_radix(v.trim().trim_start_matches("0x"), 16).ok()
+        });
+        let flag = match split.next()?.trim() {
+            "R" => RasBadPageFlag::Reserved,
+            "P" => RasBadPageFlag::Pending,
+            "F" => RasBadPageFlag::Failed,
+            _ => return None,
+        };
+
+        Some(Self { page: page?, size: size?, flag })
+    }
+}
+
+#[test]
+fn test_ras_bad_page_parse_line() {
+    assert_eq!(
+        RasBadPage::parse_line("0x0001a2b3 : 0x00001000 : P"),
+        Some(RasBadPage { page: 0x1a2b3, size: 0x1000, flag: RasBadPageFlag::Pending }),
+    );
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RasBadPageFlag {
+    /// The page has been retired
+    Reserved,
+    /// The page is pending to be retired
+    Pending,
+    /// Failed to retire the page
+    Failed,
+}
+
+impl fmt::Display for RasBadPageFlag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
@@ -0,0 +1,217 @@
+use crate::bindings::drm_amdgpu_info_device;
+use crate::AMDGPU::{CHIP_CLASS, DeviceHandle, GPU_INFO, HwId, IpDieEntry};
+
+/* ref: drivers/gpu/drm/amd/amdgpu/amdgpu_kms.c, `AMDGPU_INFO_DEV_INFO` */
+/* ref: mesa/src/amd/common/ac_gpu_info.c */
+
+impl DeviceHandle {
+    /// [ShaderTopology] from [drm_amdgpu_info_device] and `ip_discovery/die/0/` in sysfs (if available)
+    pub fn get_shader_topology(&self) -> Result<ShaderTopology, i32> {
+        let info = self.device_info()?;
+        let die = self.get_sysfs_path().ok().and_then(|path| {
+            IpDieEntry::get_from_sysfs_with_die_id(0, path.join("ip_discovery/die/"))
+        });
+
+        Ok(ShaderTopology::new(&info, die.as_ref()))
+    }
+}
+
+/// Physical layout of the shader engines (SE), shader arrays (SA/SH), WGPs and CUs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShaderTopology {
+    pub chip_class: CHIP_CLASS,
+    pub shader_engines: Vec<ShaderEngine>,
+    /// GC instances (XCCs) missing from IP discovery, the kernel does not list harvested XCCs.
+    /// Only the gaps before the last listed instance can be detected.
+    pub harvested_xccs: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShaderEngine {
+    pub index: u32,
+    /// All CUs in the SE are disabled
+    pub harvested: bool,
+    pub shader_arrays: Vec<ShaderArray>,
+    pub render_backends: Vec<RenderBackend>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShaderArray {
+    pub index: u32,
+    pub harvested: bool,
+    /// Before GFX10, a WGP has only one CU
+    pub wgps: Vec<Wgp>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Wgp {
+    pub index: u32,
+    pub cus: Vec<ComputeUnit>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ComputeUnit {
+    /// CU index in the SA
+    pub index: u32,
+    pub enabled: bool,
+    /// from `cu_ao_bitmap`
+    pub always_on: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RenderBackend {
+    /// RB index in the GPU
+    pub index: u32,
+    pub enabled: bool,
+}
+
+impl Wgp {
+    pub fn enabled(&self) -> bool {
+        self.cus.iter().any(|cu| cu.enabled)
+    }
+}
+
+impl ShaderArray {
+    pub fn active_cu_count(&self) -> u32 {
+        self.wgps.iter().flat_map(|wgp| &wgp.cus).filter(|cu| cu.enabled).count() as u32
+    }
+}
+
+impl ShaderEngine {
+    pub fn active_cu_count(&self) -> u32 {
+        self.shader_arrays.iter().map(|sa| sa.active_cu_count()).sum()
+    }
+
+    pub fn active_rb_count(&self) -> u32 {
+        self.render_backends.iter().filter(|rb| rb.enabled).count() as u32
+    }
+}
+
+impl ShaderTopology {
+    /// The GC instances in `die` are used to find the harvested XCCs.
+    pub fn new(info: &drm_amdgpu_info_device, die: Option<&IpDieEntry>) -> Self {
+        let chip_class = info.get_chip_class();
+        let cu_group = chip_class.cu_group() as u32;
+        let num_se = info.max_se();
+        let num_sa = info.max_sa_per_se();
+        let cu_per_sa = info.num_cu_per_sh.min(32);
+        let rb_per_se = info.num_rb_pipes.checked_div(num_se).unwrap_or(0);
+        let rb_mask = ((info.enabled_rb_pipes_mask_hi as u64) << 32) | info.enabled_rb_pipes_mask as u64;
+        let harvested_xccs = die
+            .and_then(|die| die.ip_hw_ids.iter().find(|ip| ip.hw_id == HwId::GC))
+            .map(|gc| Self::missing_instances(gc.instances.iter().map(|inst| inst.num_instance)))
+            .unwrap_or_default();
+
+        let shader_engines = (0..num_se).map(|se| {
+            let shader_arrays: Vec<ShaderArray> = (0..num_sa).map(|sa| {
+                let cu_mask = Self::bitmap(&info.cu_bitmap, se, sa);
+                let ao_mask = Self::bitmap(&info.cu_ao_bitmap, se, sa);
+                let wgps: Vec<Wgp> = (0..cu_per_sa.div_ceil(cu_group)).map(|wgp| {
+                    let cus = (wgp * cu_group..((wgp + 1) * cu_group).min(cu_per_sa)).map(|cu| {
+                        ComputeUnit {
+                            index: cu,
+                            enabled: (cu_mask >> cu) & 0b1 == 1,
+                            always_on: (ao_mask >> cu) & 0b1 == 1,
+                        }
+                    }).collect();
+
+                    Wgp { index: wgp, cus }
+                }).collect();
+                let harvested = !wgps.iter().any(|wgp| wgp.enabled());
+
+                ShaderArray { index: sa, harvested, wgps }
+            }).collect();
+            let render_backends = (se * rb_per_se..(se + 1) * rb_per_se).map(|rb| {
+                RenderBackend { index: rb, enabled: rb < 64 && (rb_mask >> rb) & 0b1 == 1 }
+            }).collect();
+            let harvested = shader_arrays.iter().all(|sa| sa.harvested);
+
+            ShaderEngine { index: se, harvested, shader_arrays, render_backends }
+        }).collect();
+
+        Self { chip_class, shader_engines, harvested_xccs }
+    }
+
+    fn missing_instances<I: Iterator<Item = u8>>(instances: I) -> Vec<u8> {
+        let listed: Vec<u8> = instances.collect();
+        let Some(last) = listed.iter().max() else { return Vec::new() };
+
+        (0..*last).filter(|i| !listed.contains(i)).collect()
+    }
+
+    /// `cu_bitmap[se % 4][sa + (se / 4) * 2]`, for up to 8 SEs
+    fn bitmap(bitmap: &[[u32; 4]; 4], se: u32, sa: u32) -> u32 {
+        let i = (se % 4) as usize;
+        let j = (sa + (se / 4) * 2) as usize;
+
+        bitmap.get(i).and_then(|b| b.get(j)).copied().unwrap_or(0)
+    }
+
+    pub fn active_cu_count(&self) -> u32 {
+        self.shader_engines.iter().map(|se| se.active_cu_count()).sum()
+    }
+
+    pub fn active_rb_count(&self) -> u32 {
+        self.shader_engines.iter().map(|se| se.active_rb_count()).sum()
+    }
+}
+
+#[test]
+fn test_shader_topology() {
+    use crate::AMDGPU::{IpHwId, IpHwInstance};
+
+    let mut info: drm_amdgpu_info_device = unsafe { core::mem::zeroed() };
+    /* Navi21, 4 SEs, 2 SAs per SE, 10 CUs per SA */
+    info.family = crate::bindings::AMDGPU_FAMILY_NV;
+    info.external_rev = 0x28; // Navi21
+    info.num_shader_engines = 4;
+    info.num_shader_arrays_per_engine = 2;
+    info.num_cu_per_sh = 10;
+    info.num_rb_pipes = 16;
+    info.enabled_rb_pipes_mask = 0x0FFF;
+    info.cu_bitmap[0] = [0x3FF, 0x3FF, 0, 0];
+    info.cu_bitmap[1] = [0x3FF, 0x0FF, 0, 0];
+    info.cu_bitmap[2] = [0x3FF, 0x3FF, 0, 0];
+    info.cu_bitmap[3] = [0, 0, 0, 0];
+    info.cu_ao_bitmap[0][0] = 0b11;
+
+    let topology = ShaderTopology::new(&info, None);
+
+    assert_eq!(topology.chip_class, CHIP_CLASS::GFX10_3);
+    assert_eq!(topology.active_cu_count(), 58);
+    assert_eq!(topology.active_rb_count(), 12);
+
+    let se1_sa1 = &topology.shader_engines[1].shader_arrays[1];
+    assert_eq!(se1_sa1.wgps.len(), 5);
+    assert!(!se1_sa1.wgps[4].enabled());
+    assert!(topology.shader_engines[0].shader_arrays[0].wgps[0].cus[1].always_on);
+    assert!(topology.shader_engines[3].harvested);
+    assert!(topology.shader_engines[3].render_backends.iter().all(|rb| !rb.enabled));
+
+    /* `harvest` in sysfs is the IP variant, not the harvest state of the XCC */
+    let gc = |num_instance: u8| IpHwInstance {
+        hw_id: HwId::GC,
+        num_instance,
+        major: 9,
+        minor: 4,
+        revision: 3,
+        harvest: 1,
+        num_base_addresses: 0,
+        base_address: Vec::new(),
+    };
+    let die = IpDieEntry { die_id: 0, ip_hw_ids: vec![IpHwId { hw_id: HwId::GC, instances: vec![gc(0)] }] };
+    let topology = ShaderTopology::new(&info, Some(&die));
+
+    assert!(topology.harvested_xccs.is_empty());
+    assert_eq!(topology.shader_engines.iter().filter(|se| se.harvested).count(), 1);
+
+    /* harvested XCCs are not listed */
+    let die = IpDieEntry {
+        die_id: 0,
+        ip_hw_ids: vec![IpHwId { hw_id: HwId::GC, instances: vec![gc(0), gc(2), gc(3), gc(5)] }],
+    };
+    let topology = ShaderTopology::new(&info, Some(&die));
+
+    assert_eq!(topology.harvested_xccs, vec![1, 4]);
+    assert_eq!(topology.shader_engines.iter().filter(|se| se.harvested).count(), 1);
+}
@@ -0,0 +1,309 @@
+use std::collections::HashMap;
+use std::fmt;
+use super::PPTable;
+
+/// A field that differs between two tables
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDiff {
+    /// e.g. `V11_0_0.smc_pptable.SocketPowerLimitAc[0]`
+    pub path: String,
+    /// `None` if the field only exists in the new table
+    pub old: Option<String>,
+    /// `None` if the field only exists in the old table
+    pub new: Option<String>,
+}
+
+impl fmt::Display for FieldDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} -> {}",
+            self.path,
+            self.old.as_deref().unwrap_or("-"),
+            self.new.as_deref().unwrap_or("-"),
+        )
+    }
+}
+
+impl FieldDiff {
+    pub(crate) fn with_prefix(mut self, prefix: &str) -> Self {
+        self.path = if self.path.is_empty() {
+            prefix.to_string()
+        } else {
+            format!("{prefix}.{}", self.path)
+        };
+
+        self
+    }
+}
+
+/// Compare two values field by field.
+/// The fields are walked through the pretty-printed `Debug` output (`{:#?}`),
+/// so any table with `#[derive(Debug)]` (including the bindings) can be compared.
+/// Struct type names and `Some(..)` are omitted from the paths.
+/// Byte images without a decoder should be compared with [diff_bytes] instead,
+/// a slice is printed as one leaf per element.
+///
+/// The `Debug` format is not guaranteed to be stable by std,
+/// and a custom `Debug` impl can silently produce wrong paths.
+/// It is intended for the bindgen structs and the types deriving `Debug` over them.
+pub fn diff_fields<T: fmt::Debug + ?Sized>(old: &T, new: &T) -> Vec<FieldDiff> {
+    let old = flatten_debug(&format!("{old:#?}"));
+    let new = flatten_debug(&format!("{new:#?}"));
+    let old_map: HashMap<&str, &str> = old.iter().map(|(p, v)| (p.as_str(), v.as_str())).collect();
+    let new_map: HashMap<&str, &str> = new.iter().map(|(p, v)| (p.as_str(), v.as_str())).collect();
+
+    let changed_or_removed = old.iter().filter_map(|(path, old_val)| {
+        let new_val = new_map.get(path.as_str()).copied();
+
+        if new_val == Some(old_val.as_str()) { return None }
+
+        Some(FieldDiff {
+            path: path.clone(),
+            old: Some(old_val.clone()),
+            new: new_val.map(|v| v.to_string()),
+        })
+    });
+    let added = new.iter().filter(|(path, _)| !old_map.contains_key(path.as_str())).map(|(path, new_val)| {
+        FieldDiff { path: path.clone(), old: None, new: Some(new_val.clone()) }
+    });
+
+    changed_or_removed.chain(added).collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Frame {
+    Struct,
+    Array,
+    Tuple,
+}
+
+/// (path, value) of each leaf in the `{:#?}` output
+fn flatten_debug(s: &str) -> Vec<(String, String)> {
+    let mut stack: Vec<(Frame, String, usize)> = Vec::new();
+    let mut leaves = Vec::new();
+
+    for line in s.lines() {
+        let l = line.trim().trim_end_matches(',');
+
+        if l.is_empty() { continue }
+        if matches!(l, "}" | "]" | ")") {
+            stack.pop();
+            continue;
+        }
+
+        let (name, value) = match l.split_once(": ") {
+            Some((name, value)) if name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') => (Some(name), value),
+            _ => (None, l),
+        };
+        let (frame, head) = if value.starts_with(['"', '\'']) {
+            /* a string or char value may end with " {", "[" or "(" */
+            (None, value)
+        } else if let Some(head) = value.strip_suffix(" {") {
+            (Some(Frame::Struct), head)
+        } else if let Some(head) = value.strip_suffix('[') {
+            (Some(Frame::Array), head)
+        } else if let Some(head) = value.strip_suffix('(') {
+            (Some(Frame::Tuple), head)
+        } else {
+            (None, value)
+        };
+
+        let path = match (name, stack.last_mut()) {
+            (Some(name), Some((_, parent, _))) if !parent.is_empty() => format!("{parent}.{name}"),
+            (Some(name), _) => name.to_string(),
+            (None, Some((Frame::Array, parent, i))) => {
+                *i += 1;
+                format!("{parent}[{}]", *i - 1)
+            },
+            (None, Some((Frame::Tuple, parent, i))) => {
+                *i += 1;
+                match *i - 1 {
+                    0 => parent.clone(),
+                    n if parent.is_empty() => n.to_string(),
+                    n => format!("{parent}.{n}"),
+                }
+            },
+            (None, Some((Frame::Struct, parent, _))) => parent.clone(),
+            /* variant name of a top-level enum, e.g. `V11_0_0` of `PPTable` */
+            (None, None) if frame == Some(Frame::Tuple) && head != "Some" => head.to_string(),
+            (None, None) => String::new(),
+        };
+
+        match frame {
+            Some(frame) => stack.push((frame, path, 0)),
+            None => leaves.push((path, head.to_string())),
+        }
+    }
+
+    leaves
+}
+
+/// Compare two byte slices, each run of differing bytes is reported as one [FieldDiff]
+/// with the path `bytes[start..end]` and the bytes in hex.
+/// The bytes beyond the end of the shorter slice are reported as one run.
+pub fn diff_bytes(old: &[u8], new: &[u8]) -> Vec<FieldDiff> {
+    let hex = |b: &[u8]| b.iter().map(|b| format!("{b:02X}")).collect::<Vec<_>>().join(" ");
+    let path = |start: usize, end: usize| format!("bytes[{start:#X}..{end:#X}]");
+    let common = old.len().min(new.len());
+    let mut diffs = Vec::new();
+    let mut i = 0;
+
+    while i < common {
+        if old[i] == new[i] {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+
+        while i < common && old[i] != new[i] { i += 1 }
+
+        diffs.push(FieldDiff {
+            path: path(start, i),
+            old: Some(hex(&old[start..i])),
+            new: Some(hex(&new[start..i])),
+        });
+    }
+
+    if old.len() != new.len() {
+        let rest = |b: &[u8]| Some(&b[common..]).filter(|b| !b.is_empty()).map(hex);
+
+        diffs.push(FieldDiff {
+            path: path(common, old.len().max(new.len())),
+            old: rest(old),
+            new: rest(new),
+        });
+    }
+
+    diffs
+}
+
+impl PPTable {
+    /// Compare the bindgen structs of the same variant with [diff_fields],
+    /// the paths start with the variant name.
+    /// Tables of different variants have no common path,
+    /// all the fields are reported as removed and added.
+    pub fn diff(&self, other: &Self) -> Vec<FieldDiff> {
+        macro_rules! diff {
+            ($($variant:ident),*) => {
+                match (self, other) {
+                    $(
+                        (Self::$variant(old), Self::$variant(new)) => diff_fields(old, new)
+                            .into_iter()
+                            .map(|d| d.with_prefix(stringify!($variant)))
+                            .collect(),
+                    )*
+                    (Self::Invalid, Self::Invalid) => Vec::new(),
+                    /* the derived `Debug` of `PPTable` only wraps the bindgen structs */
+                    _ => diff_fields(self, other),
+                }
+            };
+        }
+
+        diff!(Tonga, Vega10, Vega20, V11_0_0, V11_0_7, V13_0_0, V13_0_7, V14_0_2, Unknown)
+    }
+}
+
+#[test]
+fn test_diff_fields() {
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Inner {
+        a: [u16; 3],
+        b: Option<u32>,
+    }
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    enum Table {
+        V1(Inner),
+        V2(Inner),
+    }
+
+    let old = Table::V1(Inner { a: [1, 2, 3], b: None });
+    let new = Table::V1(Inner { a: [1, 5, 3], b: Some(4) });
+    let diff = diff_fields(&old, &new);
+
+    assert_eq!(diff, vec![
+        FieldDiff { path: "V1.a[1]".to_string(), old: Some("2".to_string()), new: Some("5".to_string()) },
+        FieldDiff { path: "V1.b".to_string(), old: Some("None".to_string()), new: Some("4".to_string()) },
+    ]);
+    assert!(diff_fields(&old, &old).is_empty());
+
+    let other = Table::V2(Inner { a: [1, 2, 3], b: None });
+    let diff = diff_fields(&old, &other);
+
+    assert!(diff.iter().any(|d| d.path == "V1.a[0]" && d.new.is_none()));
+    assert!(diff.iter().any(|d| d.path == "V2.a[0]" && d.old.is_none()));
+}
+
+#[test]
+fn test_diff_fields_string_value() {
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Name {
+        name: &'static str,
+        id: [u8; 2],
+    }
+
+    let old = Name { name: "a: b {", id: [0, 1] };
+    let new = Name { name: "a: c {", id: [0, 2] };
+
+    assert_eq!(diff_fields(&old, &new), vec![
+        FieldDiff { path: "name".to_string(), old: Some("\"a: b {\"".to_string()), new: Some("\"a: c {\"".to_string()) },
+        FieldDiff { path: "id[1]".to_string(), old: Some("1".to_string()), new: Some("2".to_string()) },
+    ]);
+}
+
+#[test]
+fn test_diff_bytes() {
+    let diff = diff_bytes(&[0, 1, 2, 3, 4], &[0, 5, 6, 3, 7, 8, 9]);
+
+    assert_eq!(diff, vec![
+        FieldDiff { path: "bytes[0x1..0x3]".to_string(), old: Some("01 02".to_string()), new: Some("05 06".to_string()) },
+        FieldDiff { path: "bytes[0x4..0x5]".to_string(), old: Some("04".to_string()), new: Some("07".to_string()) },
+        FieldDiff { path: "bytes[0x5..0x7]".to_string(), old: None, new: Some("08 09".to_string()) },
+    ]);
+    assert!(diff_bytes(&[1, 2], &[1, 2]).is_empty());
+}
+
+#[test]
+fn test_pp_table_diff() {
+    use core::mem::size_of;
+    use crate::bindings::ppt::smu_v11_0_0_ppt::smu_11_0_powerplay_table;
+
+    let size = size_of::<smu_11_0_powerplay_table>();
+    let mut bytes = vec![0u8; size];
+    bytes[0..2].copy_from_slice(&(size as u16).to_le_bytes());
+    bytes[2] = 12; // Navi10
+
+    let old = PPTable::decode(&bytes).unwrap();
+    let mut new = old.clone();
+
+    assert!(old.diff(&new).is_empty());
+    assert!(new.set_socket_power_limit(200, 150));
+
+    assert_eq!(old.diff(&new), vec![
+        FieldDiff {
+            path: "V11_0_0.smc_pptable.SocketPowerLimitAc[0]".to_string(),
+            old: Some("0".to_string()),
+            new: Some("200".to_string()),
+        },
+        FieldDiff {
+            path: "V11_0_0.smc_pptable.SocketPowerLimitDc[0]".to_string(),
+            old: Some("0".to_string()),
+            new: Some("150".to_string()),
+        },
+    ]);
+
+    let other = PPTable::Unknown(crate::bindings::atom_common_table_header {
+        structuresize: 0,
+        format_revision: 1,
+        content_revision: 0,
+    });
+    let diff = old.diff(&other);
+
+    assert!(diff.iter().any(|d| d.path == "V11_0_0.header.format_revision" && d.new.is_none()));
+    assert!(diff.iter().any(|d| d.path == "Unknown.format_revision" && d.old.is_none()));
+}
@@ -0,0 +1,748 @@
+use std::fmt;
+use crate::bindings::atom_rom_header_v2_2;
+use super::vbios_parser::VbiosParser;
+use super::vbios_table_list::AtomTableEntry;
+
+// ref: drivers/gpu/drm/amd/amdgpu/atom.c
+// ref: drivers/gpu/drm/amd/amdgpu/atom-bits.h
+// ref: drivers/gpu/drm/amd/amdgpu/atom-names.h
+
+/* ATOM_CT_WS_PTR, ATOM_CT_PS_PTR, ATOM_CT_CODE_PTR */
+const CT_WS_PTR: usize = 4;
+const CT_PS_PTR: usize = 5;
+const CT_CODE_PTR: usize = 6;
+const CASE_MAGIC: u8 = 0x63;
+const CASE_END: u16 = 0x5A5A;
+
+impl VbiosParser {
+    /// Disassemble all command tables listed in `atom_master_list_of_command_functions_v2_1`
+    pub fn disassemble_command_tables(
+        &self,
+        rom_header: &atom_rom_header_v2_2,
+    ) -> Vec<AtomDisassembly> {
+        self.command_tables(rom_header).map(|entry| AtomDisassembly::from_entry(&entry)).collect()
+    }
+}
+
+/// Listing of a command table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AtomDisassembly {
+    pub index: usize,
+    pub name: Option<&'static str>,
+    /// Offset of the table in the VBIOS image
+    pub offset: usize,
+    pub format_revision: u8,
+    pub content_revision: u8,
+    /// Size of the workspace, in dwords
+    pub ws: u8,
+    /// Size of the parameter space, in bytes
+    pub ps: u8,
+    pub instructions: Vec<AtomInstruction>,
+}
+
+impl AtomDisassembly {
+    pub fn from_entry(entry: &AtomTableEntry) -> Self {
+        let mut dis = Self::disassemble(entry.bytes);
+
+        dis.index = entry.index;
+        dis.name = entry.name;
+        dis.offset = entry.offset;
+
+        dis
+    }
+
+    /// `table` starts with `atom_common_table_header`
+    pub fn disassemble(table: &[u8]) -> Self {
+        let ws = table.get(CT_WS_PTR).copied().unwrap_or(0);
+        let ps = table.get(CT_PS_PTR).map(|ps| ps & 0x7F).unwrap_or(0);
+        let mut instructions = Vec::new();
+        let mut ptr = CT_CODE_PTR;
+
+        while ptr < table.len() {
+            let inst = AtomInstruction::decode(table, ptr);
+            let len = inst.len;
+
+            instructions.push(inst);
+
+            if len == 0 { break }
+
+            ptr += len;
+        }
+
+        Self {
+            index: 0,
+            name: None,
+            offset: 0,
+            format_revision: table.get(2).copied().unwrap_or(0),
+            content_revision: table.get(3).copied().unwrap_or(0),
+            ws,
+            ps,
+            instructions,
+        }
+    }
+}
+
+impl fmt::Display for AtomDisassembly {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "command table {:#04x} ({}) @ {:#06x}, rev {}.{}, WS: {}, PS: {}",
+            self.index,
+            self.name.unwrap_or("unknown"),
+            self.offset,
+            self.format_revision,
+            self.content_revision,
+            self.ws,
+            self.ps,
+        )?;
+
+        for inst in &self.instructions {
+            writeln!(f, "  {inst}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// `ATOM_ARG_*`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AtomArgType {
+    REG,
+    PS,
+    WS,
+    FB,
+    ID,
+    IMM,
+    PLL,
+    MC,
+}
+
+impl AtomArgType {
+    const fn from_u8(v: u8) -> Self {
+        match v & 0x7 {
+            0 => Self::REG,
+            1 => Self::PS,
+            2 => Self::WS,
+            3 => Self::FB,
+            4 => Self::ID,
+            5 => Self::IMM,
+            6 => Self::PLL,
+            _ => Self::MC,
+        }
+    }
+
+    /// Destination type encoded in the opcode, `REG`, `PS`, `WS`, `FB`, `PLL`, `MC` in order
+    const fn from_dst_index(i: u8) -> Self {
+        match i {
+            0 => Self::REG,
+            1 => Self::PS,
+            2 => Self::WS,
+            3 => Self::FB,
+            4 => Self::PLL,
+            _ => Self::MC,
+        }
+    }
+}
+
+/// `ATOM_SRC_*`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AtomAlign {
+    DWORD,
+    WORD0,
+    WORD8,
+    WORD16,
+    BYTE0,
+    BYTE8,
+    BYTE16,
+    BYTE24,
+}
+
+impl AtomAlign {
+    const fn from_u8(v: u8) -> Self {
+        match v & 0x7 {
+            0 => Self::DWORD,
+            1 => Self::WORD0,
+            2 => Self::WORD8,
+            3 => Self::WORD16,
+            4 => Self::BYTE0,
+            5 => Self::BYTE8,
+            6 => Self::BYTE16,
+            _ => Self::BYTE24,
+        }
+    }
+
+    /// Size of the immediate value
+    pub const fn imm_size(&self) -> usize {
+        match self {
+            Self::DWORD => 4,
+            Self::WORD0 | Self::WORD8 | Self::WORD16 => 2,
+            _ => 1,
+        }
+    }
+
+    pub const fn bit_range(&self) -> &str {
+        match self {
+            Self::DWORD => "[31:0]",
+            Self::WORD0 => "[15:0]",
+            Self::WORD8 => "[23:8]",
+            Self::WORD16 => "[31:16]",
+            Self::BYTE0 => "[7:0]",
+            Self::BYTE8 => "[15:8]",
+            Self::BYTE16 => "[23:16]",
+            Self::BYTE24 => "[31:24]",
+        }
+    }
+}
+
+/* atom_dst_to_src */
+const DST_TO_SRC: [[u8; 4]; 8] = [
+    [0, 0, 0, 0],
+    [1, 2, 3, 0],
+    [1, 2, 3, 0],
+    [1, 2, 3, 0],
+    [4, 5, 6, 7],
+    [4, 5, 6, 7],
+    [4, 5, 6, 7],
+    [4, 5, 6, 7],
+];
+/* atom_def_dst */
+const DEF_DST: [u8; 8] = [0, 0, 1, 2, 0, 1, 2, 3];
+
+/* `attr &= 0x38; attr |= atom_def_dst[attr >> 3] << 6;`,
+ * the destination alignment of CLEAR/SHIFT_LEFT/SHIFT_RIGHT follows the source alignment */
+fn def_dst_attr(attr: u8) -> u8 {
+    (attr & 0x38) | (DEF_DST[((attr >> 3) & 0x7) as usize] << 6)
+}
+
+/// Source or destination operand
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AtomArg {
+    pub type_: AtomArgType,
+    pub align: AtomAlign,
+    /// Register/parameter/workspace index, or the immediate value
+    pub value: u32,
+}
+
+impl AtomArg {
+    /// `atom_get_src_int`, returns the operand and its length
+    fn decode(type_: AtomArgType, align: AtomAlign, bin: &[u8], ptr: usize) -> Option<(Self, usize)> {
+        let (value, len) = match type_ {
+            AtomArgType::REG |
+            AtomArgType::ID => (read_u16(bin, ptr)? as u32, 2),
+            AtomArgType::PS |
+            AtomArgType::WS |
+            AtomArgType::FB |
+            AtomArgType::PLL |
+            AtomArgType::MC => (*bin.get(ptr)? as u32, 1),
+            AtomArgType::IMM => {
+                let size = align.imm_size();
+
+                (read_imm(bin, ptr, size)?, size)
+            },
+        };
+
+        Some((Self { type_, align, value }, len))
+    }
+
+    fn ws_name(idx: u32) -> Option<&'static str> {
+        let name = match idx {
+            0x40 => "QUOTIENT",
+            0x41 => "REMAINDER",
+            0x42 => "DATAPTR",
+            0x43 => "SHIFT",
+            0x44 => "OR_MASK",
+            0x45 => "AND_MASK",
+            0x46 => "FB_WINDOW",
+            0x47 => "ATTRIBUTES",
+            0x48 => "REGPTR",
+            _ => return None,
+        };
+
+        Some(name)
+    }
+}
+
+impl fmt::Display for AtomArg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.type_ {
+            AtomArgType::IMM => return write!(f, "{:#x}", self.value),
+            AtomArgType::REG => write!(f, "REG[{:#06x}]", self.value)?,
+            AtomArgType::ID => write!(f, "ID[{:#06x}]", self.value)?,
+            AtomArgType::WS => if let Some(name) = Self::ws_name(self.value) {
+                write!(f, "WS[{name}]")?
+            } else {
+                write!(f, "WS[{}]", self.value)?
+            },
+            _ => write!(f, "{:?}[{}]", self.type_, self.value)?,
+        }
+
+        if self.align != AtomAlign::DWORD {
+            write!(f, " {}", self.align.bit_range())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Condition of `JUMP_*`, `ATOM_COND_*`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AtomJumpCond {
+    Always,
+    Equal,
+    Below,
+    Above,
+    BelowOrEqual,
+    AboveOrEqual,
+    NotEqual,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AtomOpcode {
+    Move(AtomArgType),
+    And(AtomArgType),
+    Or(AtomArgType),
+    ShiftLeft(AtomArgType),
+    ShiftRight(AtomArgType),
+    Mul(AtomArgType),
+    Div(AtomArgType),
+    Add(AtomArgType),
+    Sub(AtomArgType),
+    SetAtiPort,
+    SetPciPort,
+    SetSysIoPort,
+    SetRegBlock,
+    SetFbBase,
+    Compare(AtomArgType),
+    Switch,
+    Jump(AtomJumpCond),
+    Test(AtomArgType),
+    DelayMillisec,
+    DelayMicrosec,
+    CallTable,
+    Repeat,
+    Clear(AtomArgType),
+    Nop,
+    Eot,
+    Mask(AtomArgType),
+    PostCard,
+    Beep,
+    SaveReg,
+    RestoreReg,
+    SetDataBlock,
+    Xor(AtomArgType),
+    Shl(AtomArgType),
+    Shr(AtomArgType),
+    Debug,
+    ProcessDs,
+    Mul32(AtomArgType),
+    Div32(AtomArgType),
+    Reserved(u8),
+}
+
+impl AtomOpcode {
+    pub fn from_u8(op: u8) -> Self {
+        /* opcodes with a destination type are in groups of 6 */
+        let group = |base: u8| AtomArgType::from_dst_index(op - base);
+
+        match op {
+            1..=6 => Self::Move(group(1)),
+            7..=12 => Self::And(group(7)),
+            13..=18 => Self::Or(group(13)),
+            19..=24 => Self::ShiftLeft(group(19)),
+            25..=30 => Self::ShiftRight(group(25)),
+            31..=36 => Self::Mul(group(31)),
+            37..=42 => Self::Div(group(37)),
+            43..=48 => Self::Add(group(43)),
+            49..=54 => Self::Sub(group(49)),
+            55 => Self::SetAtiPort,
+            56 => Self::SetPciPort,
+            57 => Self::SetSysIoPort,
+            58 => Self::SetRegBlock,
+            59 => Self::SetFbBase,
+            60..=65 => Self::Compare(group(60)),
+            66 => Self::Switch,
+            67 => Self::Jump(AtomJumpCond::Always),
+            68 => Self::Jump(AtomJumpCond::Equal),
+            69 => Self::Jump(AtomJumpCond::Below),
+            70 => Self::Jump(AtomJumpCond::Above),
+            71 => Self::Jump(AtomJumpCond::BelowOrEqual),
+            72 => Self::Jump(AtomJumpCond::AboveOrEqual),
+            73 => Self::Jump(AtomJumpCond::NotEqual),
+            74..=79 => Self::Test(group(74)),
+            80 => Self::DelayMillisec,
+            81 => Self::DelayMicrosec,
+            82 => Self::CallTable,
+            83 => Self::Repeat,
+            84..=89 => Self::Clear(group(84)),
+            90 => Self::Nop,
+            91 => Self::Eot,
+            92..=97 => Self::Mask(group(92)),
+            98 => Self::PostCard,
+            99 => Self::Beep,
+            100 => Self::SaveReg,
+            101 => Self::RestoreReg,
+            102 => Self::SetDataBlock,
+            103..=108 => Self::Xor(group(103)),
+            109..=114 => Self::Shl(group(109)),
+            115..=120 => Self::Shr(group(115)),
+            121 => Self::Debug,
+            122 => Self::ProcessDs,
+            123..=128 => Self::Mul32(group(123)),
+            129..=134 => Self::Div32(group(129)),
+            _ => Self::Reserved(op),
+        }
+    }
+
+    pub fn mnemonic(&self) -> String {
+        let (name, dst) = match self {
+            Self::Move(dst) => ("MOVE", dst),
+            Self::And(dst) => ("AND", dst),
+            Self::Or(dst) => ("OR", dst),
+            Self::ShiftLeft(dst) => ("SHIFT_LEFT", dst),
+            Self::ShiftRight(dst) => ("SHIFT_RIGHT", dst),
+            Self::Mul(dst) => ("MUL", dst),
+            Self::Div(dst) => ("DIV", dst),
+            Self::Add(dst) => ("ADD", dst),
+            Self::Sub(dst) => ("SUB", dst),
+            Self::Compare(dst) => ("COMPARE", dst),
+            Self::Test(dst) => ("TEST", dst),
+            Self::Clear(dst) => ("CLEAR", dst),
+            Self::Mask(dst) => ("MASK", dst),
+            Self::Xor(dst) => ("XOR", dst),
+            Self::Shl(dst) => ("SHL", dst),
+            Self::Shr(dst) => ("SHR", dst),
+            Self::Mul32(dst) => ("MUL32", dst),
+            Self::Div32(dst) => ("DIV32", dst),
+            Self::Jump(cond) => {
+                let s = match cond {
+                    AtomJumpCond::Always => "JUMP",
+                    AtomJumpCond::Equal => "JUMP_EQUAL",
+                    AtomJumpCond::Below => "JUMP_BELOW",
+                    AtomJumpCond::Above => "JUMP_ABOVE",
+                    AtomJumpCond::BelowOrEqual => "JUMP_BELOW_OR_EQUAL",
+                    AtomJumpCond::AboveOrEqual => "JUMP_ABOVE_OR_EQUAL",
+                    AtomJumpCond::NotEqual => "JUMP_NOT_EQUAL",
+                };
+
+                return s.to_string();
+            },
+            Self::Reserved(op) => return format!("RESERVED_{op:#04x}"),
+            Self::SetAtiPort => return "SET_ATI_PORT".to_string(),
+            Self::SetPciPort => return "SET_PCI_PORT".to_string(),
+            Self::SetSysIoPort => return "SET_SYS_IO_PORT".to_string(),
+            Self::SetRegBlock => return "SET_REG_BLOCK".to_string(),
+            Self::SetFbBase => return "SET_FB_BASE".to_string(),
+            Self::Switch => return "SWITCH".to_string(),
+            Self::DelayMillisec => return "DELAY_MILLISEC".to_string(),
+            Self::DelayMicrosec => return "DELAY_MICROSEC".to_string(),
+            Self::CallTable => return "CALL_TABLE".to_string(),
+            Self::Repeat => return "REPEAT".to_string(),
+            Self::Nop => return "NOP".to_string(),
+            Self::Eot => return "EOT".to_string(),
+            Self::PostCard => return "POST_CARD".to_string(),
+            Self::Beep => return "BEEP".to_string(),
+            Self::SaveReg => return "SAVE_REG".to_string(),
+            Self::RestoreReg => return "RESTORE_REG".to_string(),
+            Self::SetDataBlock => return "SET_DATA_BLOCK".to_string(),
+            Self::Debug => return "DEBUG".to_string(),
+            Self::ProcessDs => return "PROCESS_DS".to_string(),
+        };
+
+        format!("{name}_{dst:?}")
+    }
+}
+
+impl fmt::Display for AtomOpcode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.mnemonic())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AtomInstruction {
+    /// Offset from the start of the command table
+    pub offset: usize,
+    pub opcode: AtomOpcode,
+    pub attr: Option<u8>,
+    pub dst: Option<AtomArg>,
+    pub src: Option<AtomArg>,
+    /// Immediate operand: port, register block, jump target, delay, table index,
+    /// shift count, mask, POST code or length of the data block
+    pub imm: Option<u32>,
+    /// `(value, target)` of `SWITCH`
+    pub cases: Vec<(u32, u16)>,
+    /// 0 if the instruction is truncated or invalid
+    pub len: usize,
+}
+
+impl AtomInstruction {
+    pub fn decode(table: &[u8], offset: usize) -> Self {
+        let opcode = AtomOpcode::from_u8(table.get(offset).copied().unwrap_or(0));
+        let mut inst = Self {
+            offset,
+            opcode,
+            attr: None,
+            dst: None,
+            src: None,
+            imm: None,
+            cases: Vec::new(),
+            len: 0,
+        };
+
+        if let Some(len) = inst.decode_operands(table, offset + 1) {
+            inst.len = len + 1;
+        }
+
+        inst
+    }
+
+    /// Returns the length of the operands
+    fn decode_operands(&mut self, bin: &[u8], start: usize) -> Option<usize> {
+        let mut ptr = start;
+
+        match self.opcode {
+            AtomOpcode::Move(dst) |
+            AtomOpcode::And(dst) |
+            AtomOpcode::Or(dst) |
+            AtomOpcode::Mul(dst) |
+            AtomOpcode::Div(dst) |
+            AtomOpcode::Add(dst) |
+            AtomOpcode::Sub(dst) |
+            AtomOpcode::Compare(dst) |
+            AtomOpcode::Test(dst) |
+            AtomOpcode::Xor(dst) |
+            AtomOpcode::Shl(dst) |
+            AtomOpcode::Shr(dst) |
+            AtomOpcode::Mul32(dst) |
+            AtomOpcode::Div32(dst) => {
+                let attr = self.read_attr(bin, &mut ptr)?;
+                self.read_dst(dst, attr, bin, &mut ptr)?;
+                self.read_src(attr, bin, &mut ptr)?;
+            },
+            AtomOpcode::ShiftLeft(dst) |
+            AtomOpcode::ShiftRight(dst) => {
+                let attr = def_dst_attr(self.read_attr(bin, &mut ptr)?);
+                self.read_dst(dst, attr, bin, &mut ptr)?;
+                self.imm = Some(self.read_imm(bin, &mut ptr, 1)?);
+            },
+            AtomOpcode::Clear(dst) => {
+                let attr = def_dst_attr(self.read_attr(bin, &mut ptr)?);
+                self.read_dst(dst, attr, bin, &mut ptr)?;
+            },
+            AtomOpcode::Mask(dst) => {
+                let attr = self.read_attr(bin, &mut ptr)?;
+                self.read_dst(dst, attr, bin, &mut ptr)?;
+                let size = AtomAlign::from_u8(attr >> 3).imm_size();
+                self.imm = Some(self.read_imm(bin, &mut ptr, size)?);
+                self.read_src(attr, bin, &mut ptr)?;
+            },
+            AtomOpcode::SetFbBase => {
+                let attr = self.read_attr(bin, &mut ptr)?;
+                self.read_src(attr, bin, &mut ptr)?;
+            },
+            AtomOpcode::Switch => {
+                let attr = self.read_attr(bin, &mut ptr)?;
+                self.read_src(attr, bin, &mut ptr)?;
+
+                while read_u16(bin, ptr)? != CASE_END {
+                    if *bin.get(ptr)? != CASE_MAGIC { return None }
+                    ptr += 1;
+
+                    let align = AtomAlign::from_u8(attr >> 3);
+                    let (val, len) = AtomArg::decode(AtomArgType::IMM, align, bin, ptr)?;
+                    ptr += len;
+                    let target = read_u16(bin, ptr)?;
+                    ptr += 2;
+
+                    self.cases.push((val.value, target));
+                }
+
+                ptr += 2;
+            },
+            AtomOpcode::SetAtiPort |
+            AtomOpcode::SetRegBlock |
+            AtomOpcode::Jump(_) => {
+                self.imm = Some(self.read_imm(bin, &mut ptr, 2)?);
+            },
+            AtomOpcode::SetPciPort |
+            AtomOpcode::SetSysIoPort |
+            AtomOpcode::DelayMillisec |
+            AtomOpcode::DelayMicrosec |
+            AtomOpcode::CallTable |
+            AtomOpcode::PostCard |
+            AtomOpcode::SetDataBlock |
+            AtomOpcode::Debug => {
+                self.imm = Some(self.read_imm(bin, &mut ptr, 1)?);
+            },
+            AtomOpcode::ProcessDs => {
+                let len = self.read_imm(bin, &mut ptr, 2)?;
+                self.imm = Some(len);
+                ptr += len as usize;
+
+                if ptr > bin.len() { return None }
+            },
+            AtomOpcode::Repeat |
+            AtomOpcode::Nop |
+            AtomOpcode::Eot |
+            AtomOpcode::Beep |
+            AtomOpcode::SaveReg |
+            AtomOpcode::RestoreReg => {},
+            AtomOpcode::Reserved(_) => return None,
+        }
+
+        Some(ptr - start)
+    }
+
+    fn read_attr(&mut self, bin: &[u8], ptr: &mut usize) -> Option<u8> {
+        let attr = *bin.get(*ptr)?;
+        *ptr += 1;
+        self.attr = Some(attr);
+
+        Some(attr)
+    }
+
+    fn read_imm(&self, bin: &[u8], ptr: &mut usize, size: usize) -> Option<u32> {
+        let v = read_imm(bin, *ptr, size)?;
+        *ptr += size;
+
+        Some(v)
+    }
+
+    /* atom_get_dst */
+    fn read_dst(&mut self, type_: AtomArgType, attr: u8, bin: &[u8], ptr: &mut usize) -> Option<()> {
+        let align = DST_TO_SRC[((attr >> 3) & 0x7) as usize][((attr >> 6) & 0x3) as usize];
+        let (arg, len) = AtomArg::decode(type_, AtomAlign::from_u8(align), bin, *ptr)?;
+        *ptr += len;
+        self.dst = Some(arg);
+
+        Some(())
+    }
+
+    /* atom_get_src */
+    fn read_src(&mut self, attr: u8, bin: &[u8], ptr: &mut usize) -> Option<()> {
+        let type_ = AtomArgType::from_u8(attr);
+        let align = AtomAlign::from_u8(attr >> 3);
+        let (arg, len) = AtomArg::decode(type_, align, bin, *ptr)?;
+        *ptr += len;
+        self.src = Some(arg);
+
+        Some(())
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.len != 0
+    }
+}
+
+impl fmt::Display for AtomInstruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04x}: ", self.offset)?;
+
+        if !self.is_valid() {
+            return write!(f, "{} <invalid or truncated>", self.opcode);
+        }
+
+        write!(f, "{:<20}", self.opcode.mnemonic())?;
+
+        let mut operands: Vec<String> = Vec::with_capacity(3);
+
+        if let Some(dst) = &self.dst {
+            operands.push(dst.to_string());
+        }
+
+        match self.opcode {
+            AtomOpcode::Mask(_) => {
+                if let Some(mask) = self.imm {
+                    operands.push(format!("{mask:#x}"));
+                }
+            },
+            AtomOpcode::Jump(_) => {
+                if let Some(target) = self.imm {
+                    operands.push(format!("{target:04x}"));
+                }
+            },
+            AtomOpcode::ProcessDs => {
+                if let Some(len) = self.imm {
+                    operands.push(format!("{len} bytes"));
+                }
+            },
+            _ => if let Some(imm) = self.imm {
+                operands.push(format!("{imm:#x}"));
+            },
+        }
+
+        if let Some(src) = &self.src {
+            operands.push(src.to_string());
+        }
+
+        for (val, target) in &self.cases {
+            operands.push(format!("case {val:#x}: {target:04x}"));
+        }
+
+        write!(f, "{}", operands.join(", "))
+    }
+}
+
+fn read_u16(bin: &[u8], ptr: usize) -> Option<u16> {
+    bin.get(ptr..ptr+2).map(|b| u16::from_le_bytes([b[0], b[1]]))
+}
+
+fn read_imm(bin: &[u8], ptr: usize, size: usize) -> Option<u32> {
+    let b = bin.get(ptr..ptr+size)?;
+
+    Some(b.iter().rev().fold(0u32, |acc, v| (acc << 8) | *v as u32))
+}
+
+#[test]
+fn test_atom_disassemble() {
+    let mut table: Vec<u8> = vec![
+        0x00, 0x00, 0x01, 0x01, // header
+        0x02, 0x08, // WS: 2, PS: 8
+        0x01, 0x01, 0x34, 0x12, 0x00, // MOVE_REG REG[0x1234], PS[0]
+        0x03, 0x0D, 0x40, 0xFF, 0x00, // MOVE_WS WS[QUOTIENT] [15:0], 0xff
+        0x3C, 0x25, 0x00, 0x41, 0x01, // COMPARE_REG REG[0x4100] [7:0], 0x1
+        0x44, 0x06, 0x00, // JUMP_EQUAL 0006
+        0x50, 0x0A, // DELAY_MILLISEC 10
+        0x52, 0x0C, // CALL_TABLE 0xc
+        0x5B, // EOT
+    ];
+    let size = table.len() as u16;
+    table[0..2].copy_from_slice(&size.to_le_bytes());
+
+    let dis = AtomDisassembly::disassemble(&table);
+    let inst = &dis.instructions;
+
+    assert_eq!(dis.ws, 2);
+    assert_eq!(dis.ps, 8);
+    assert_eq!(inst.len(), 7);
+    assert_eq!(inst[0].to_string(), "0006: MOVE_REG            REG[0x1234], PS[0]");
+    assert_eq!(inst[1].to_string(), "000b: MOVE_WS             WS[QUOTIENT] [15:0], 0xff");
+    assert_eq!(inst[2].to_string(), "0010: COMPARE_REG         REG[0x4100] [7:0], 0x1");
+    assert_eq!(inst[3].to_string(), "0015: JUMP_EQUAL          0006");
+    assert_eq!(inst[4].imm, Some(10));
+    assert_eq!(inst[5].opcode, AtomOpcode::CallTable);
+    assert_eq!(inst[6].opcode, AtomOpcode::Eot);
+    assert!(inst.iter().all(|i| i.is_valid()));
+}
+
+#[test]
+fn test_atom_disassemble_shift() {
+    let mut table: Vec<u8> = vec![
+        0x00, 0x00, 0x01, 0x01, // header
+        0x00, 0x00, // WS: 0, PS: 0
+        0x13, 0x28, 0x34, 0x12, 0x04, // SHIFT_LEFT_REG REG[0x1234] [15:8], 4
+        0x5B, // EOT
+    ];
+    let size = table.len() as u16;
+    table[0..2].copy_from_slice(&size.to_le_bytes());
+
+    let dis = AtomDisassembly::disassemble(&table);
+    let inst = &dis.instructions;
+
+    assert_eq!(inst.len(), 2);
+    assert_eq!(inst[0].opcode, AtomOpcode::ShiftLeft(AtomArgType::REG));
+    /* the destination alignment is taken from the source alignment (BYTE8) */
+    assert_eq!(inst[0].dst.unwrap().align, AtomAlign::BYTE8);
+    assert_eq!(inst[0].imm, Some(4));
+    assert_eq!(inst[0].to_string(), "0006: SHIFT_LEFT_REG      REG[0x1234] [15:8], 0x4");
+}
@@ -0,0 +1,484 @@
+use core::mem::{offset_of, size_of};
+use std::fmt;
+use crate::bindings::{
+    atom_master_data_table_v2_1,
+    atom_common_record_header,
+    atom_i2c_record,
+    atom_hpd_int_record,
+    atom_encoder_caps_record,
+    atom_disp_connector_caps_record,
+    atom_connector_hpdpin_lut_record,
+    atom_connector_auxddc_lut_record,
+    atom_connector_forced_tmds_cap_record,
+    atom_bracket_layout_record,
+    atom_connector_layout_info,
+    atom_display_object_path_v2,
+    atom_display_object_path_v3,
+    display_object_info_table_v1_4,
+    display_object_info_table_v1_5,
+    atom_object_record_type_id_ATOM_I2C_RECORD_TYPE,
+    atom_object_record_type_id_ATOM_HPD_INT_RECORD_TYPE,
+    atom_object_record_type_id_ATOM_CONNECTOR_HPDPIN_LUT_RECORD_TYPE,
+    atom_object_record_type_id_ATOM_CONNECTOR_AUXDDC_LUT_RECORD_TYPE,
+    atom_object_record_type_id_ATOM_ENCODER_CAP_RECORD_TYPE,
+    atom_object_record_type_id_ATOM_BRACKET_LAYOUT_RECORD_TYPE,
+    atom_object_record_type_id_ATOM_CONNECTOR_FORCED_TMDS_CAP_RECORD_TYPE,
+    atom_object_record_type_id_ATOM_DISP_CONNECTOR_CAPS_RECORD_TYPE,
+    atom_object_record_type_id_ATOM_RECORD_END_TYPE,
+};
+use super::vbios_parser::VbiosParser;
+
+// ref: drivers/gpu/drm/amd/display/dc/bios/bios_parser2.c
+// ref: drivers/gpu/drm/amd/include/object_id.h
+
+impl VbiosParser {
+    pub fn get_display_object_info(
+        &self,
+        data_table: &atom_master_data_table_v2_1,
+    ) -> Option<VbiosDisplayObjectInfo> {
+        let offset = data_table.listOfdatatables.displayobjectinfo as usize;
+        let header = self.read_header(offset)?;
+
+        /* v1.4 and v1.5 share the layout except for `display_path` */
+        let (supported_devices, number_of_path, path_offset, path_size) = match (
+            header.format_revision,
+            header.content_revision,
+        ) {
+            (1, 4) => {
+                let t: display_object_info_table_v1_4 = self.read_struct(offset)?;
+
+                (
+                    t.supporteddevices,
+                    t.number_of_path,
+                    offset_of!(display_object_info_table_v1_4, display_path),
+                    size_of::<atom_display_object_path_v2>(),
+                )
+            },
+            (1, 5) => {
+                let t: display_object_info_table_v1_5 = self.read_struct(offset)?;
+
+                (
+                    t.supporteddevices,
+                    t.number_of_path,
+                    offset_of!(display_object_info_table_v1_5, display_path),
+                    size_of::<atom_display_object_path_v3>(),
+                )
+            },
+            _ => return None,
+        };
+
+        let mut connectors = Vec::with_capacity(number_of_path as usize);
+        let mut bracket_layout = None;
+
+        for i in 0..number_of_path as usize {
+            let path_offset = offset + path_offset + i * path_size;
+            let (display_objid, disp_recordoffset, encoderobjid, extencoderobjid, encoder_recordoffset, device_tag) =
+                if header.content_revision == 4 {
+                    let Some(p) = self.read_struct::<atom_display_object_path_v2>(path_offset) else { break };
+
+                    (
+                        p.display_objid,
+                        p.disp_recordoffset,
+                        p.encoderobjid,
+                        p.extencoderobjid,
+                        p.encoder_recordoffset,
+                        p.device_tag,
+                    )
+                } else {
+                    let Some(p) = self.read_struct::<atom_display_object_path_v3>(path_offset) else { break };
+
+                    (p.display_objid, p.disp_recordoffset, p.encoderobjid, 0, 0, p.device_tag)
+                };
+
+            let mut connector = VbiosConnector {
+                object_id: AtomObjectId(display_objid),
+                encoder_id: AtomObjectId(encoderobjid),
+                ext_encoder_id: (extencoderobjid != 0).then_some(AtomObjectId(extencoderobjid)),
+                device_tag,
+                hpd_pin: None,
+                i2c_id: None,
+                connector_caps: None,
+                encoder_caps: None,
+                max_tmds_clock_mhz: None,
+                hpd_pin_lut: None,
+                aux_ddc_lut: None,
+                bracket_position: None,
+            };
+
+            /* record offsets are relative to the start of the table */
+            for (record_type, record_offset) in self.records(offset, disp_recordoffset) {
+                match record_type {
+                    atom_object_record_type_id_ATOM_HPD_INT_RECORD_TYPE => {
+                        let Some(r) = self.read_struct::<atom_hpd_int_record>(record_offset) else { continue };
+                        connector.hpd_pin = Some(r.pin_id);
+                    },
+                    atom_object_record_type_id_ATOM_I2C_RECORD_TYPE => {
+                        let Some(r) = self.read_struct::<atom_i2c_record>(record_offset) else { continue };
+                        connector.i2c_id = Some(r.i2c_id);
+                    },
+                    atom_object_record_type_id_ATOM_DISP_CONNECTOR_CAPS_RECORD_TYPE => {
+                        let Some(r) = self.read_struct::<atom_disp_connector_caps_record>(record_offset) else { continue };
+                        connector.connector_caps = Some(r.connectcaps);
+                    },
+                    atom_object_record_type_id_ATOM_CONNECTOR_FORCED_TMDS_CAP_RECORD_TYPE => {
+                        let Some(r) = self.read_struct::<atom_connector_forced_tmds_cap_record>(record_offset) else { continue };
+                        connector.max_tmds_clock_mhz = Some(r.maxtmdsclkrate_in2_5mhz as u32 * 5 / 2);
+                    },
+                    atom_object_record_type_id_ATOM_CONNECTOR_HPDPIN_LUT_RECORD_TYPE => {
+                        let Some(r) = self.read_struct::<atom_connector_hpdpin_lut_record>(record_offset) else { continue };
+                        connector.hpd_pin_lut = Some(r.hpd_pin_map);
+                    },
+                    atom_object_record_type_id_ATOM_CONNECTOR_AUXDDC_LUT_RECORD_TYPE => {
+                        let Some(r) = self.read_struct::<atom_connector_auxddc_lut_record>(record_offset) else { continue };
+                        connector.aux_ddc_lut = Some(r.aux_ddc_map);
+                    },
+                    atom_object_record_type_id_ATOM_BRACKET_LAYOUT_RECORD_TYPE => {
+                        bracket_layout = self.read_bracket_layout(record_offset);
+                    },
+                    _ => {},
+                }
+            }
+
+            for (record_type, record_offset) in self.records(offset, encoder_recordoffset) {
+                if record_type == atom_object_record_type_id_ATOM_ENCODER_CAP_RECORD_TYPE {
+                    let Some(r) = self.read_struct::<atom_encoder_caps_record>(record_offset) else { continue };
+                    connector.encoder_caps = Some(r.encodercaps);
+                }
+            }
+
+            connectors.push(connector);
+        }
+
+        if let Some(layout) = &bracket_layout {
+            for conn in connectors.iter_mut() {
+                conn.bracket_position = layout.connectors
+                    .iter()
+                    .find(|c| c.object_id == conn.object_id)
+                    .map(|c| c.position);
+            }
+        }
+
+        Some(VbiosDisplayObjectInfo {
+            format_revision: header.format_revision,
+            content_revision: header.content_revision,
+            supported_devices,
+            connectors,
+            bracket_layout,
+        })
+    }
+
+    /// `(record_type, offset)` until `ATOM_RECORD_END_TYPE`
+    fn records(&self, table_offset: usize, record_offset: u16) -> Vec<(u32, usize)> {
+        let mut records = Vec::new();
+
+        if record_offset == 0 { return records }
+
+        let mut offset = table_offset + record_offset as usize;
+
+        while let Some(h) = self.read_struct::<atom_common_record_header>(offset) {
+            if h.record_type as u32 == atom_object_record_type_id_ATOM_RECORD_END_TYPE
+            || h.record_size == 0 {
+                break;
+            }
+
+            records.push((h.record_type as u32, offset));
+            offset += h.record_size as usize;
+        }
+
+        records
+    }
+
+    fn read_bracket_layout(&self, offset: usize) -> Option<VbiosBracketLayout> {
+        let r: atom_bracket_layout_record = self.read_struct(offset)?;
+        let info_offset = offset + offset_of!(atom_bracket_layout_record, conn_info);
+        let connectors = (0..r.conn_num as usize).filter_map(|i| {
+            let info: atom_connector_layout_info = self.read_struct(
+                info_offset + i * size_of::<atom_connector_layout_info>()
+            )?;
+
+            Some(VbiosConnectorLayout {
+                object_id: AtomObjectId(info.connectorobjid),
+                connector_type: info.connector_type,
+                position: info.position,
+            })
+        }).collect();
+
+        Some(VbiosBracketLayout {
+            length: r.bracketlen,
+            width: r.bracketwidth,
+            connectors,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VbiosDisplayObjectInfo {
+    pub format_revision: u8,
+    pub content_revision: u8,
+    /// `ATOM_DISPLAY_*_SUPPORT`
+    pub supported_devices: u16,
+    pub connectors: Vec<VbiosConnector>,
+    pub bracket_layout: Option<VbiosBracketLayout>,
+}
+
+/// A display path from `displayobjectinfo`, with its connector and encoder records
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VbiosConnector {
+    pub object_id: AtomObjectId,
+    pub encoder_id: AtomObjectId,
+    /// v1.4 only
+    pub ext_encoder_id: Option<AtomObjectId>,
+    pub device_tag: u16,
+    pub hpd_pin: Option<u8>,
+    pub i2c_id: Option<u8>,
+    /// `ATOM_CONNECTOR_CAP_*`
+    pub connector_caps: Option<u32>,
+    /// `ATOM_ENCODER_CAP_RECORD_*`, v1.4 only
+    pub encoder_caps: Option<u32>,
+    pub max_tmds_clock_mhz: Option<u32>,
+    pub hpd_pin_lut: Option<[u8; 8]>,
+    pub aux_ddc_lut: Option<[u8; 8]>,
+    pub bracket_position: Option<u8>,
+}
+
+impl VbiosConnector {
+    pub fn connector_type(&self) -> AtomConnectorType {
+        AtomConnectorType::from(self.object_id.id())
+    }
+
+    /// DDC/AUX line, `I2C_HW_LANE_MUX` of `i2c_id`
+    pub fn ddc_line(&self) -> Option<u8> {
+        self.i2c_id.map(|id| id & 0xF)
+    }
+
+    pub fn has_hw_i2c(&self) -> Option<bool> {
+        self.i2c_id.map(|id| (id & 0x80) != 0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VbiosBracketLayout {
+    /// mm
+    pub length: u8,
+    /// mm
+    pub width: u8,
+    pub connectors: Vec<VbiosConnectorLayout>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VbiosConnectorLayout {
+    pub object_id: AtomObjectId,
+    /// `CONNECTOR_TYPE_*`
+    pub connector_type: u8,
+    pub position: u8,
+}
+
+/// Graphics object ID, `[14:12]` object type, `[11:8]` enum ID, `[7:0]` object ID
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AtomObjectId(pub u16);
+
+impl AtomObjectId {
+    pub const GRAPH_OBJECT_TYPE_GPU: u8 = 0x1;
+    pub const GRAPH_OBJECT_TYPE_ENCODER: u8 = 0x2;
+    pub const GRAPH_OBJECT_TYPE_CONNECTOR: u8 = 0x3;
+    pub const GRAPH_OBJECT_TYPE_ROUTER: u8 = 0x4;
+    pub const GRAPH_OBJECT_TYPE_GENERIC: u8 = 0x7;
+
+    pub fn object_type(&self) -> u8 {
+        ((self.0 >> 12) & 0x7) as u8
+    }
+
+    pub fn enum_id(&self) -> u8 {
+        ((self.0 >> 8) & 0xF) as u8
+    }
+
+    pub fn id(&self) -> u8 {
+        (self.0 & 0xFF) as u8
+    }
+
+    /// "UNIPHY A" .. "UNIPHY G" for internal UNIPHY encoders
+    pub fn uniphy_name(&self) -> Option<String> {
+        if self.object_type() != Self::GRAPH_OBJECT_TYPE_ENCODER { return None }
+
+        let base = match self.id() {
+            0x1E => b'A',
+            0x20 => b'C',
+            0x21 => b'E',
+            0x25 => b'G',
+            _ => return None,
+        };
+        let link = match self.enum_id() {
+            1 => base,
+            2 => base + 1,
+            _ => return None,
+        };
+
+        Some(format!("UNIPHY {}", link as char))
+    }
+}
+
+/// `CONNECTOR_OBJECT_ID_*`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AtomConnectorType {
+    SingleLinkDVII,
+    DualLinkDVII,
+    SingleLinkDVID,
+    DualLinkDVID,
+    VGA,
+    Composite,
+    SVIDEO,
+    YPbPr,
+    DConnector,
+    DIN_9Pin,
+    SCART,
+    HDMITypeA,
+    HDMITypeB,
+    LVDS,
+    DIN_7Pin,
+    PCIE,
+    Crossfire,
+    HardcodeDVI,
+    DisplayPort,
+    eDP,
+    MXM,
+    LVDS_eDP,
+    USBC,
+    Unknown(u8),
+}
+
+impl From<u8> for AtomConnectorType {
+    fn from(id: u8) -> Self {
+        match id {
+            0x01 => Self::SingleLinkDVII,
+            0x02 => Self::DualLinkDVII,
+            0x03 => Self::SingleLinkDVID,
+            0x04 => Self::DualLinkDVID,
+            0x05 => Self::VGA,
+            0x06 => Self::Composite,
+            0x07 => Self::SVIDEO,
+            0x08 => Self::YPbPr,
+            0x09 => Self::DConnector,
+            0x0A => Self::DIN_9Pin,
+            0x0B => Self::SCART,
+            0x0C => Self::HDMITypeA,
+            0x0D => Self::HDMITypeB,
+            0x0E => Self::LVDS,
+            0x0F => Self::DIN_7Pin,
+            0x10 => Self::PCIE,
+            0x11 => Self::Crossfire,
+            0x12 => Self::HardcodeDVI,
+            0x13 => Self::DisplayPort,
+            0x14 => Self::eDP,
+            0x15 => Self::MXM,
+            0x16 => Self::LVDS_eDP,
+            0x18 => Self::USBC,
+            _ => Self::Unknown(id),
+        }
+    }
+}
+
+impl AtomConnectorType {
+    /* ref: drivers/gpu/drm/amd/display/amdgpu_dm/amdgpu_dm.c, to_drm_connector_type */
+    #[cfg(any(feature = "link_drm", feature = "dynamic_loading"))]
+    pub fn to_drm_connector_type(&self) -> crate::drmModeConnectorType {
+        use crate::drmModeConnectorType;
+
+        match self {
+            Self::SingleLinkDVII |
+            Self::DualLinkDVII => drmModeConnectorType::DVII,
+            Self::SingleLinkDVID |
+            Self::DualLinkDVID |
+            Self::HardcodeDVI => drmModeConnectorType::DVID,
+            Self::VGA => drmModeConnectorType::VGA,
+            Self::Composite => drmModeConnectorType::Composite,
+            Self::SVIDEO => drmModeConnectorType::SVIDEO,
+            Self::YPbPr => drmModeConnectorType::Component,
+            Self::DIN_9Pin => drmModeConnectorType::DIN_9Pin,
+            Self::HDMITypeA => drmModeConnectorType::HDMIA,
+            Self::HDMITypeB => drmModeConnectorType::HDMIB,
+            Self::LVDS => drmModeConnectorType::LVDS,
+            Self::DisplayPort |
+            Self::USBC => drmModeConnectorType::DisplayPort,
+            Self::eDP |
+            Self::LVDS_eDP => drmModeConnectorType::eDP,
+            _ => drmModeConnectorType::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for AtomConnectorType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[test]
+fn test_display_object_info_v1_4() {
+    const TABLE_OFFSET: usize = 0x10;
+    let path_offset = TABLE_OFFSET + offset_of!(display_object_info_table_v1_4, display_path);
+    let record_offset = (offset_of!(display_object_info_table_v1_4, display_path)
+        + size_of::<atom_display_object_path_v2>()) as u16;
+
+    let mut bin = vec![0u8; path_offset + size_of::<atom_display_object_path_v2>()];
+    let t = &mut bin[TABLE_OFFSET..];
+    t[2] = 1; // format_revision
+    t[3] = 4; // content_revision
+    t[offset_of!(display_object_info_table_v1_4, number_of_path)] = 1;
+
+    let p = &mut bin[path_offset..];
+    p[0..2].copy_from_slice(&0x3113u16.to_le_bytes()); // DisplayPort, enum 1
+    p[2..4].copy_from_slice(&record_offset.to_le_bytes());
+    p[4..6].copy_from_slice(&0x2120u16.to_le_bytes()); // UNIPHY1, enum 1
+
+    bin.extend_from_slice(&[1, 4, 0x93, 0]); // I2C
+    bin.extend_from_slice(&[2, 4, 3, 1]); // HPD
+    bin.extend_from_slice(&[21, 12, 120, 20, 1, 0, 0x13, 0x31, 5, 2]); // bracket layout
+    bin.extend_from_slice(&[0xFF, 0]);
+    /* the image does not end with the table */
+    bin.resize(bin.len() + 0x100, 0);
+
+    let mut data_table: atom_master_data_table_v2_1 = unsafe { core::mem::zeroed() };
+    data_table.listOfdatatables.displayobjectinfo = TABLE_OFFSET as u16;
+
+    let info = VbiosParser::new(bin).get_display_object_info(&data_table).unwrap();
+    let conn = &info.connectors[0];
+
+    assert_eq!(conn.connector_type(), AtomConnectorType::DisplayPort);
+    assert_eq!(conn.encoder_id.uniphy_name(), Some("UNIPHY C".to_string()));
+    assert_eq!(conn.ddc_line(), Some(3));
+    assert_eq!(conn.has_hw_i2c(), Some(true));
+    assert_eq!(conn.hpd_pin, Some(3));
+    assert_eq!(conn.bracket_position, Some(2));
+    assert_eq!(info.bracket_layout.unwrap().length, 120);
+}
+
+#[test]
+fn test_display_object_info_truncated() {
+    const TABLE_OFFSET: usize = 0x10;
+    let table_size = size_of::<display_object_info_table_v1_4>();
+    let path_offset = TABLE_OFFSET + offset_of!(display_object_info_table_v1_4, display_path);
+    /* the records are at the end of the image, the HPD record is truncated */
+    let record_offset = (table_size - 6) as u16;
+
+    let mut bin = vec![0u8; TABLE_OFFSET + table_size];
+    let t = &mut bin[TABLE_OFFSET..];
+    t[2] = 1; // format_revision
+    t[3] = 4; // content_revision
+    /* the paths after `display_path[7]` are out of the image */
+    t[offset_of!(display_object_info_table_v1_4, number_of_path)] = 0xFF;
+    t[table_size-6..].copy_from_slice(&[1, 4, 0x93, 0, 2, 4]); // I2C, HPD
+
+    let p = &mut bin[path_offset..];
+    p[0..2].copy_from_slice(&0x3113u16.to_le_bytes()); // DisplayPort, enum 1
+    p[2..4].copy_from_slice(&record_offset.to_le_bytes());
+
+    let mut data_table: atom_master_data_table_v2_1 = unsafe { core::mem::zeroed() };
+    data_table.listOfdatatables.displayobjectinfo = TABLE_OFFSET as u16;
+
+    let info = VbiosParser::new(bin).get_display_object_info(&data_table).unwrap();
+
+    assert_eq!(info.connectors.len(), 8);
+    assert_eq!(info.connectors[0].connector_type(), AtomConnectorType::DisplayPort);
+    assert_eq!(info.connectors[0].ddc_line(), Some(3));
+    assert_eq!(info.connectors[0].hpd_pin, None);
+}
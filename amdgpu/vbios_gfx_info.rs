@@ -0,0 +1,226 @@
+use crate::bindings::{
+    atom_master_data_table_v2_1,
+    atom_gfx_info_v2_2,
+    atom_gfx_info_v2_3,
+    atom_gfx_info_v2_4,
+    atom_gfx_info_v2_7,
+    atom_gfx_info_v3_0,
+};
+use super::vbios_parser::VbiosParser;
+
+// ref: drivers/gpu/drm/amd/amdgpu/amdgpu_atomfirmware.c, amdgpu_atomfirmware_get_gfx_info
+
+macro_rules! gfx_info_common {
+    ($h: expr) => {
+        VbiosGfxInfo {
+            format_revision: $h.table_header.format_revision,
+            content_revision: $h.table_header.content_revision,
+            gfxip_min_ver: $h.gfxip_min_ver,
+            gfxip_max_ver: $h.gfxip_max_ver,
+            max_shader_engines: $h.max_shader_engines,
+            max_sh_per_se: $h.max_sh_per_se,
+            max_cu_per_sh: $h.max_cu_per_sh,
+            max_backends_per_se: $h.max_backends_per_se,
+            max_texture_channel_caches: $h.max_texture_channel_caches,
+            max_tile_pipes: None,
+            active_cu_per_sh: None,
+            active_rb_per_se: None,
+            active_cu_total: None,
+            active_wgp_per_se: None,
+            active_se: None,
+            gc_double_offchip_lds_buffer: None,
+            gc_config: None,
+            inactive_cu_per_se: Vec::new(),
+            inactive_sa_mask: None,
+            inactive_wgp: Vec::new(),
+            inactive_rb: Vec::new(),
+        }
+    };
+}
+
+impl VbiosParser {
+    pub fn get_gfx_info(
+        &self,
+        data_table: &atom_master_data_table_v2_1,
+    ) -> Option<VbiosGfxInfo> {
+        let offset = data_table.listOfdatatables.gfx_info as usize;
+        let header = self.read_header(offset)?;
+
+        let info = match (header.format_revision, header.content_revision) {
+            (2, 2) => {
+                let h: atom_gfx_info_v2_2 = self.read_struct(offset)?;
+
+                VbiosGfxInfo {
+                    max_tile_pipes: Some(h.max_tile_pipes),
+                    ..gfx_info_common!(h)
+                }
+            },
+            (2, 3) => {
+                let h: atom_gfx_info_v2_3 = self.read_struct(offset)?;
+
+                VbiosGfxInfo {
+                    max_tile_pipes: Some(h.max_tile_pipes),
+                    active_cu_per_sh: Some(h.active_cu_per_sh),
+                    active_rb_per_se: Some(h.active_rb_per_se),
+                    ..gfx_info_common!(h)
+                }
+            },
+            (2, 4) => {
+                let h: atom_gfx_info_v2_4 = self.read_struct(offset)?;
+
+                VbiosGfxInfo {
+                    active_cu_per_sh: Some(h.active_cu_per_sh),
+                    active_rb_per_se: Some(h.active_rb_per_se),
+                    gc_double_offchip_lds_buffer: Some(h.gc_double_offchip_lds_buffer),
+                    ..gfx_info_common!(h)
+                }
+            },
+            (2, 7) => {
+                let h: atom_gfx_info_v2_7 = self.read_struct(offset)?;
+                let num_se = (h.max_shader_engines as usize).min(h.inactive_cu_per_se.len());
+
+                VbiosGfxInfo {
+                    active_cu_per_sh: Some(h.active_cu_per_sh),
+                    active_rb_per_se: Some(h.active_rb_per_se),
+                    active_cu_total: Some(h.active_cu_total),
+                    gc_double_offchip_lds_buffer: Some(h.gc_double_offchip_lds_buffer),
+                    gc_config: Some(h.gc_config),
+                    inactive_cu_per_se: h.inactive_cu_per_se[..num_se].to_vec(),
+                    ..gfx_info_common!(h)
+                }
+            },
+            (3, 0) => {
+                let h: atom_gfx_info_v3_0 = self.read_struct(offset)?;
+
+                VbiosGfxInfo {
+                    max_tile_pipes: Some(h.max_tile_pipes),
+                    active_rb_per_se: Some(h.active_rb_per_se),
+                    active_wgp_per_se: Some(h.active_wgp_per_se),
+                    active_se: Some(h.active_se),
+                    gc_config: Some(h.gc_config),
+                    inactive_sa_mask: Some(h.inactive_sa_mask),
+                    inactive_wgp: h.inactive_wgp.to_vec(),
+                    inactive_rb: h.inactive_rb.to_vec(),
+                    ..gfx_info_common!(h)
+                }
+            },
+            _ => return None,
+        };
+
+        Some(info)
+    }
+}
+
+/// Normalized `atom_gfx_info_v2_2` .. `atom_gfx_info_v3_0`,
+/// fields not present in the table version are `None` or empty
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VbiosGfxInfo {
+    pub format_revision: u8,
+    pub content_revision: u8,
+    pub gfxip_min_ver: u8,
+    pub gfxip_max_ver: u8,
+    pub max_shader_engines: u8,
+    pub max_sh_per_se: u8,
+    pub max_cu_per_sh: u8,
+    pub max_backends_per_se: u8,
+    pub max_texture_channel_caches: u8,
+    /// v2.2, v2.3, v3.0
+    pub max_tile_pipes: Option<u8>,
+    /// v2.3, v2.4, v2.7
+    pub active_cu_per_sh: Option<u8>,
+    /// v2.3 -
+    pub active_rb_per_se: Option<u8>,
+    /// v2.7
+    pub active_cu_total: Option<u8>,
+    /// v3.0
+    pub active_wgp_per_se: Option<u8>,
+    /// v3.0
+    pub active_se: Option<u8>,
+    /// v2.4, v2.7
+    pub gc_double_offchip_lds_buffer: Option<u8>,
+    /// v2.7, v3.0
+    pub gc_config: Option<u32>,
+    /// Number of the harvested CUs for each SE (v2.7)
+    pub inactive_cu_per_se: Vec<u8>,
+    /// v3.0
+    pub inactive_sa_mask: Option<u32>,
+    /// Bitmap of the harvested WGPs for each SA (SH) (v3.0)
+    pub inactive_wgp: Vec<u8>,
+    /// Bitmap of the harvested RBs (v3.0)
+    pub inactive_rb: Vec<u8>,
+}
+
+impl VbiosGfxInfo {
+    pub fn max_cu_per_se(&self) -> u32 {
+        self.max_sh_per_se as u32 * self.max_cu_per_sh as u32
+    }
+
+    /// Number of the active CUs for each SE, only available with v2.7
+    pub fn active_cu_per_se(&self) -> Option<Vec<u32>> {
+        if self.inactive_cu_per_se.is_empty() { return None }
+
+        let max = self.max_cu_per_se();
+
+        Some(self.inactive_cu_per_se.iter().map(|inactive| max.saturating_sub(*inactive as u32)).collect())
+    }
+
+    /// Bitmap of the active CUs for each SA (SH), only available with v3.0.  
+    /// A WGP consists of 2 CUs.
+    pub fn active_cu_bitmap(&self) -> Option<Vec<u32>> {
+        if self.inactive_wgp.is_empty() { return None }
+
+        let num_sa = (self.max_shader_engines as usize * self.max_sh_per_se as usize)
+            .min(self.inactive_wgp.len());
+        let inactive_sa_mask = self.inactive_sa_mask.unwrap_or(0);
+        let wgp_per_sa = (self.max_cu_per_sh / 2) as u32;
+        let wgp_mask = 1u32.checked_shl(wgp_per_sa).map(|v| v - 1).unwrap_or(u32::MAX);
+
+        let bitmap = self.inactive_wgp[..num_sa].iter().enumerate().map(|(sa, inactive)| {
+            if (inactive_sa_mask >> sa) & 0b1 == 1 { return 0 }
+
+            let active_wgp = !(*inactive as u32) & wgp_mask;
+
+            (0..wgp_per_sa.min(16)).fold(0u32, |cu, wgp| {
+                if (active_wgp >> wgp) & 0b1 == 1 {
+                    cu | (0b11 << (wgp * 2))
+                } else {
+                    cu
+                }
+            })
+        }).collect();
+
+        Some(bitmap)
+    }
+}
+
+#[test]
+fn test_gfx_info_active_cu_bitmap() {
+    use core::mem::offset_of;
+
+    let mut data_table: atom_master_data_table_v2_1 = unsafe { core::mem::zeroed() };
+    data_table.listOfdatatables.gfx_info = 0x10;
+
+    let mut bin = vec![0u8; 0x10 + core::mem::size_of::<atom_gfx_info_v3_0>()];
+    let h = &mut bin[0x10..];
+    h[0..2].copy_from_slice(&(core::mem::size_of::<atom_gfx_info_v3_0>() as u16).to_le_bytes());
+    h[2] = 3; // format_revision
+    h[3] = 0; // content_revision
+    h[offset_of!(atom_gfx_info_v3_0, max_shader_engines)] = 2;
+    h[offset_of!(atom_gfx_info_v3_0, max_cu_per_sh)] = 10;
+    h[offset_of!(atom_gfx_info_v3_0, max_sh_per_se)] = 2;
+    h[offset_of!(atom_gfx_info_v3_0, inactive_sa_mask)] = 0b1000;
+    h[offset_of!(atom_gfx_info_v3_0, inactive_wgp)] = 0b1_0000;
+
+    let gfx_info = VbiosParser::new(bin.clone()).get_gfx_info(&data_table).unwrap();
+
+    assert_eq!(gfx_info.max_cu_per_se(), 20);
+    assert_eq!(
+        gfx_info.active_cu_bitmap(),
+        Some(vec![0b00_1111_1111, 0b11_1111_1111, 0b11_1111_1111, 0]),
+    );
+
+    /* the layout of v2.5 and v2.6 is not defined */
+    bin[0x12] = 2;
+    bin[0x13] = 5;
+    assert_eq!(VbiosParser::new(bin).get_gfx_info(&data_table), None);
+}
@@ -0,0 +1,216 @@
+use std::fmt;
+use crate::AMDGPU::VRAM_TYPE;
+use crate::bindings::{
+    atom_master_data_table_v2_1,
+    atom_firmware_info_v3_1,
+    atom_integrated_system_info_v1_11,
+    atom_integrated_system_info_v1_12,
+    atom_integrated_system_info_v2_1,
+    atom_integrated_system_info_v2_2,
+    atom_dmi_t17_mem_type_def_Ddr2MemType,
+    atom_dmi_t17_mem_type_def_Ddr3MemType,
+    atom_dmi_t17_mem_type_def_Ddr4MemType,
+    atom_dmi_t17_mem_type_def_Ddr5MemType,
+    atom_dmi_t17_mem_type_def_LpDdr3MemType,
+    atom_dmi_t17_mem_type_def_LpDdr4MemType,
+    atom_dmi_t17_mem_type_def_LpDdr5MemType,
+};
+use super::vbios_parser::VbiosParser;
+
+// ref: drivers/gpu/drm/amd/amdgpu/amdgpu_atomfirmware.c, amdgpu_atomfirmware_get_vram_info
+
+macro_rules! integrated_system_info_common {
+    ($h: expr) => {
+        VbiosIntegratedSystemInfo {
+            format_revision: $h.table_header.format_revision,
+            content_revision: $h.table_header.content_revision,
+            vbios_misc: $h.vbios_misc,
+            gpucapinfo: $h.gpucapinfo,
+            system_config: $h.system_config,
+            cpucapinfo: $h.cpucapinfo,
+            gpuclk_ss_percentage: $h.gpuclk_ss_percentage,
+            gpuclk_ss_type: $h.gpuclk_ss_type,
+            memory_type: ApuMemoryType::from($h.memorytype),
+            uma_channel_number: $h.umachannelnumber,
+            htc_hyst_limit: $h.htc_hyst_limit,
+            htc_tmp_limit: $h.htc_tmp_limit,
+            boot_sclk: None,
+            boot_mclk: None,
+        }
+    };
+}
+
+impl VbiosParser {
+    /// `boot_sclk` and `boot_mclk` are filled from `firmwareinfo`,
+    /// the atomfirmware versions of the table no longer have a DPM state table.
+    pub fn get_integrated_system_info(
+        &self,
+        data_table: &atom_master_data_table_v2_1,
+    ) -> Option<VbiosIntegratedSystemInfo> {
+        let offset = data_table.listOfdatatables.integratedsysteminfo as usize;
+        let header = self.read_header(offset)?;
+
+        let mut info = match (header.format_revision, header.content_revision) {
+            (1, 11) => {
+                let h: atom_integrated_system_info_v1_11 = self.read_struct(offset)?;
+
+                integrated_system_info_common!(h)
+            },
+            (1, 12) => {
+                let h: atom_integrated_system_info_v1_12 = self.read_struct(offset)?;
+
+                integrated_system_info_common!(h)
+            },
+            (2, 1) => {
+                let h: atom_integrated_system_info_v2_1 = self.read_struct(offset)?;
+
+                integrated_system_info_common!(h)
+            },
+            (2, 2) => {
+                let h: atom_integrated_system_info_v2_2 = self.read_struct(offset)?;
+
+                integrated_system_info_common!(h)
+            },
+            _ => return None,
+        };
+
+        /* bootup_sclk_in10khz and bootup_mclk_in10khz are at the same offset in v3.x */
+        let fw_info_offset = data_table.listOfdatatables.firmwareinfo as usize;
+        if let Some(fw_info) = self.read_header(fw_info_offset)
+            .filter(|h| h.format_revision == 3)
+            .and_then(|_| self.read_struct::<atom_firmware_info_v3_1>(fw_info_offset))
+        {
+            info.boot_sclk = Some(fw_info.bootup_sclk_in10khz);
+            info.boot_mclk = Some(fw_info.bootup_mclk_in10khz);
+        }
+
+        Some(info)
+    }
+}
+
+/// Normalized `atom_integrated_system_info_v1_11` .. `atom_integrated_system_info_v2_2`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VbiosIntegratedSystemInfo {
+    pub format_revision: u8,
+    pub content_revision: u8,
+    pub vbios_misc: u32,
+    pub gpucapinfo: u32,
+    pub system_config: u32,
+    pub cpucapinfo: u32,
+    pub gpuclk_ss_percentage: u16,
+    pub gpuclk_ss_type: u16,
+    pub memory_type: ApuMemoryType,
+    pub uma_channel_number: u8,
+    pub htc_hyst_limit: u8,
+    pub htc_tmp_limit: u8,
+    /// 10 kHz
+    pub boot_sclk: Option<u32>,
+    /// 10 kHz
+    pub boot_mclk: Option<u32>,
+}
+
+impl VbiosIntegratedSystemInfo {
+    /// `umachannelnumber` is treated as 1 if it is 0
+    pub fn memory_channels(&self) -> u32 {
+        self.uma_channel_number.max(1) as u32
+    }
+
+    pub fn memory_bus_width(&self) -> u32 {
+        self.memory_channels() * self.memory_type.channel_width()
+    }
+}
+
+/// `enum atom_dmi_t17_mem_type_def`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApuMemoryType {
+    DDR2,
+    DDR3,
+    DDR4,
+    DDR5,
+    LPDDR3,
+    LPDDR4,
+    LPDDR5,
+    Unknown(u8),
+}
+
+impl From<u8> for ApuMemoryType {
+    fn from(value: u8) -> Self {
+        match value as u32 {
+            atom_dmi_t17_mem_type_def_Ddr2MemType => Self::DDR2,
+            atom_dmi_t17_mem_type_def_Ddr3MemType => Self::DDR3,
+            atom_dmi_t17_mem_type_def_Ddr4MemType => Self::DDR4,
+            atom_dmi_t17_mem_type_def_Ddr5MemType => Self::DDR5,
+            atom_dmi_t17_mem_type_def_LpDdr3MemType => Self::LPDDR3,
+            atom_dmi_t17_mem_type_def_LpDdr4MemType => Self::LPDDR4,
+            atom_dmi_t17_mem_type_def_LpDdr5MemType => Self::LPDDR5,
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+impl ApuMemoryType {
+    /* ref: convert_atom_mem_type_to_vram_type */
+    pub fn to_vram_type(&self) -> VRAM_TYPE {
+        match self {
+            Self::DDR2 => VRAM_TYPE::DDR2,
+            Self::DDR3 |
+            Self::LPDDR3 => VRAM_TYPE::DDR3,
+            Self::DDR4 => VRAM_TYPE::DDR4,
+            Self::LPDDR4 => VRAM_TYPE::LPDDR4,
+            Self::DDR5 => VRAM_TYPE::DDR5,
+            Self::LPDDR5 => VRAM_TYPE::LPDDR5,
+            Self::Unknown(_) => VRAM_TYPE::UNKNOWN,
+        }
+    }
+
+    /// bits
+    pub fn channel_width(&self) -> u32 {
+        match self {
+            Self::LPDDR5 => 32,
+            _ => 64,
+        }
+    }
+}
+
+impl fmt::Display for ApuMemoryType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[test]
+fn test_integrated_system_info_v2_2() {
+    use core::mem::{offset_of, size_of};
+
+    const SYS_INFO_OFFSET: usize = 0x10;
+    let fw_info_offset = SYS_INFO_OFFSET + size_of::<atom_integrated_system_info_v2_2>();
+
+    let mut bin = vec![0u8; fw_info_offset + size_of::<atom_firmware_info_v3_1>()];
+    let h = &mut bin[SYS_INFO_OFFSET..];
+    h[0..2].copy_from_slice(&(size_of::<atom_integrated_system_info_v2_2>() as u16).to_le_bytes());
+    h[2] = 2; // format_revision
+    h[3] = 2; // content_revision
+    h[offset_of!(atom_integrated_system_info_v2_2, memorytype)] = atom_dmi_t17_mem_type_def_LpDdr5MemType as u8;
+    h[offset_of!(atom_integrated_system_info_v2_2, umachannelnumber)] = 4;
+    h[offset_of!(atom_integrated_system_info_v2_2, htc_tmp_limit)] = 100;
+
+    let f = &mut bin[fw_info_offset..];
+    f[2] = 3; // format_revision
+    f[3] = 1; // content_revision
+    f[offset_of!(atom_firmware_info_v3_1, bootup_sclk_in10khz)..][..4].copy_from_slice(&80_000u32.to_le_bytes());
+    f[offset_of!(atom_firmware_info_v3_1, bootup_mclk_in10khz)..][..4].copy_from_slice(&60_000u32.to_le_bytes());
+
+    let mut data_table: atom_master_data_table_v2_1 = unsafe { core::mem::zeroed() };
+    data_table.listOfdatatables.integratedsysteminfo = SYS_INFO_OFFSET as u16;
+    data_table.listOfdatatables.firmwareinfo = fw_info_offset as u16;
+
+    let info = VbiosParser::new(bin).get_integrated_system_info(&data_table).unwrap();
+
+    assert_eq!((info.format_revision, info.content_revision), (2, 2));
+    assert_eq!(info.memory_type, ApuMemoryType::LPDDR5);
+    assert_eq!(info.memory_type.to_vram_type(), VRAM_TYPE::LPDDR5);
+    assert_eq!(info.memory_bus_width(), 128);
+    assert_eq!(info.htc_tmp_limit, 100);
+    assert_eq!(info.boot_sclk, Some(80_000));
+    assert_eq!(info.boot_mclk, Some(60_000));
+}
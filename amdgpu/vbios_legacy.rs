@@ -0,0 +1,206 @@
+pub use crate::bindings::atombios::{
+    ATOM_COMMON_TABLE_HEADER,
+    ATOM_ROM_HEADER,
+    ATOM_MASTER_DATA_TABLE,
+    ATOM_FIRMWARE_INFO_V2_1,
+    ATOM_FIRMWARE_INFO_V2_2,
+};
+use crate::AMDGPU::PPTable;
+use super::vbios_parser::{VbiosParser, ROM_TABLE_PTR};
+
+// ref: drivers/gpu/drm/amd/include/atombios.h
+// ref: drivers/gpu/drm/amd/amdgpu/amdgpu_atombios.c
+
+/// Getters for pre-ATOMFirmware (ATOMBIOS) images, GCN1 - GCN4 and Vega10.
+impl VbiosParser {
+    /// ATOMFirmware images have `firmwareinfo` v3.x, ATOMBIOS images have v1.x/v2.x
+    pub fn is_atomfirmware(&self) -> Option<bool> {
+        let rom_header = self.get_legacy_rom_header()?;
+        let data_table = self.get_legacy_data_table(&rom_header)?;
+        let header = self.read_header(data_table.ListOfDataTables.FirmwareInfo as usize)?;
+
+        Some(header.format_revision >= 3)
+    }
+
+    /// The common part of `ATOM_ROM_HEADER`, `ATOM_ROM_HEADER_V2_1` and `atom_rom_header_v2_2`
+    pub fn get_legacy_rom_header(&self) -> Option<ATOM_ROM_HEADER> {
+        let offset = self.read_struct::<u16>(ROM_TABLE_PTR)? as usize;
+        let rom_header: ATOM_ROM_HEADER = self.read_struct(offset)?;
+
+        if &rom_header.uaFirmWareSignature == b"ATOM" {
+            Some(rom_header)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_legacy_data_table(
+        &self,
+        rom_header: &ATOM_ROM_HEADER,
+    ) -> Option<ATOM_MASTER_DATA_TABLE> {
+        self.read_struct(rom_header.usMasterDataTableOffset as usize)
+    }
+
+    pub fn get_legacy_firmware_info(
+        &self,
+        data_table: &ATOM_MASTER_DATA_TABLE,
+    ) -> Option<LegacyFirmwareInfo> {
+        let offset = data_table.ListOfDataTables.FirmwareInfo as usize;
+        let header = self.read_header(offset)?;
+
+        let info = match (header.format_revision, header.content_revision) {
+            (2, 1) => LegacyFirmwareInfo::V2_1(self.read_struct(offset)?),
+            (2, 2) => LegacyFirmwareInfo::V2_2(self.read_struct(offset)?),
+            _ => LegacyFirmwareInfo::Unknown(self.read_struct(offset)?),
+        };
+
+        Some(info)
+    }
+
+    /// `usStructureSize` bytes from the start of `PowerPlayInfo`, including the sub-tables
+    pub fn get_legacy_powerplay_table_bytes(
+        &self,
+        data_table: &ATOM_MASTER_DATA_TABLE,
+    ) -> Option<&[u8]> {
+        let offset = data_table.ListOfDataTables.PowerPlayInfo as usize;
+        let header = self.read_header(offset)?;
+
+        self.0.get(offset..offset + header.structuresize as usize)
+    }
+
+    /// [PPTable::Tonga] (also used for Fiji) or [PPTable::Vega10]
+    pub fn get_legacy_powerplay_table(
+        &self,
+        data_table: &ATOM_MASTER_DATA_TABLE,
+    ) -> Option<PPTable> {
+        let bytes = self.get_legacy_powerplay_table_bytes(data_table)?;
+
+        PPTable::decode(bytes).ok()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum LegacyFirmwareInfo {
+    /// Cypress (Evergreen)
+    V2_1(ATOM_FIRMWARE_INFO_V2_1),
+    /// Northern Islands and later
+    V2_2(ATOM_FIRMWARE_INFO_V2_2),
+    Unknown(ATOM_COMMON_TABLE_HEADER),
+}
+
+impl LegacyFirmwareInfo {
+    pub fn header(&self) -> ATOM_COMMON_TABLE_HEADER {
+        match self {
+            Self::V2_1(t) => t.sHeader,
+            Self::V2_2(t) => t.sHeader,
+            Self::Unknown(h) => *h,
+        }
+    }
+
+    pub fn firmware_revision(&self) -> Option<u32> {
+        match self {
+            Self::V2_1(t) => Some(t.ulFirmwareRevision),
+            Self::V2_2(t) => Some(t.ulFirmwareRevision),
+            Self::Unknown(_) => None,
+        }
+    }
+
+    /// 10 kHz
+    pub fn default_engine_clock(&self) -> Option<u32> {
+        match self {
+            Self::V2_1(t) => Some(t.ulDefaultEngineClock),
+            Self::V2_2(t) => Some(t.ulDefaultEngineClock),
+            Self::Unknown(_) => None,
+        }
+    }
+
+    /// 10 kHz
+    pub fn default_memory_clock(&self) -> Option<u32> {
+        match self {
+            Self::V2_1(t) => Some(t.ulDefaultMemoryClock),
+            Self::V2_2(t) => Some(t.ulDefaultMemoryClock),
+            Self::Unknown(_) => None,
+        }
+    }
+
+    /// 10 kHz
+    pub fn core_reference_clock(&self) -> Option<u16> {
+        match self {
+            Self::V2_1(t) => Some(t.usCoreReferenceClock),
+            Self::V2_2(t) => Some(t.usCoreReferenceClock),
+            Self::Unknown(_) => None,
+        }
+    }
+
+    /// 10 kHz
+    pub fn memory_reference_clock(&self) -> Option<u16> {
+        match self {
+            Self::V2_1(t) => Some(t.usMemoryReferenceClock),
+            Self::V2_2(t) => Some(t.usMemoryReferenceClock),
+            Self::Unknown(_) => None,
+        }
+    }
+
+    /// mV
+    pub fn boot_vddc(&self) -> Option<u16> {
+        match self {
+            Self::V2_1(t) => Some(t.usBootUpVDDCVoltage),
+            Self::V2_2(t) => Some(t.usBootUpVDDCVoltage),
+            Self::Unknown(_) => None,
+        }
+    }
+
+    /// mV
+    pub fn boot_vddci(&self) -> Option<u16> {
+        match self {
+            Self::V2_2(t) => Some(t.usBootUpVDDCIVoltage),
+            _ => None,
+        }
+    }
+
+    /// mV
+    pub fn boot_mvddc(&self) -> Option<u16> {
+        match self {
+            Self::V2_2(t) => Some(t.usBootUpMVDDCVoltage),
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn test_legacy_firmware_info() {
+    use core::mem::{offset_of, size_of};
+
+    let mut bin = vec![0u8; 0x200];
+    let rom_header = 0x100usize;
+    let data_table = 0x140usize;
+    let fw_info = 0x190usize;
+
+    bin[ROM_TABLE_PTR..ROM_TABLE_PTR+2].copy_from_slice(&(rom_header as u16).to_le_bytes());
+    bin[rom_header+4..rom_header+8].copy_from_slice(b"ATOM");
+    let off = rom_header + offset_of!(ATOM_ROM_HEADER, usMasterDataTableOffset);
+    bin[off..off+2].copy_from_slice(&(data_table as u16).to_le_bytes());
+
+    let off = data_table + 4 + offset_of!(crate::bindings::atombios::ATOM_MASTER_LIST_OF_DATA_TABLES, FirmwareInfo);
+    bin[off..off+2].copy_from_slice(&(fw_info as u16).to_le_bytes());
+
+    let size = size_of::<ATOM_FIRMWARE_INFO_V2_2>() as u16;
+    bin[fw_info..fw_info+2].copy_from_slice(&size.to_le_bytes());
+    bin[fw_info+2] = 2;
+    bin[fw_info+3] = 2;
+    let off = fw_info + offset_of!(ATOM_FIRMWARE_INFO_V2_2, ulDefaultEngineClock);
+    bin[off..off+4].copy_from_slice(&120000u32.to_le_bytes());
+    let off = fw_info + offset_of!(ATOM_FIRMWARE_INFO_V2_2, usBootUpVDDCIVoltage);
+    bin[off..off+2].copy_from_slice(&950u16.to_le_bytes());
+
+    let parser = VbiosParser::new(bin);
+    let rom_header = parser.get_legacy_rom_header().unwrap();
+    let data_table = parser.get_legacy_data_table(&rom_header).unwrap();
+    let fw_info = parser.get_legacy_firmware_info(&data_table).unwrap();
+
+    assert_eq!(parser.is_atomfirmware(), Some(false));
+    assert!(matches!(fw_info, LegacyFirmwareInfo::V2_2(_)));
+    assert_eq!(fw_info.default_engine_clock(), Some(120000));
+    assert_eq!(fw_info.boot_vddci(), Some(950));
+    assert_eq!(fw_info.boot_mvddc(), Some(0));
+}
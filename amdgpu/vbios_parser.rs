@@ -1,5 +1,4 @@
-use core::mem::{size_of, MaybeUninit};
-use core::ptr;
+use core::mem::size_of;
 pub use crate::bindings::{atom_common_table_header, atom_rom_header_v2_2, atom_master_data_table_v2_1, atom_firmware_info_v3_4, atom_firmware_info_v3_5};
 use crate::AMDGPU::PPTable;
 
@@ -10,11 +9,12 @@ const SIGNATURE: &[u8] = b" 761295520";
 const SIGNATURE_OFFSET: usize = 0x30;
 const SIGNATURE_END: usize = SIGNATURE_OFFSET + SIGNATURE.len();
 const VALID_VBIOS: &[u8] = &[0x55, 0xAA];
-const ROM_TABLE_PTR: usize = 0x48;
+pub(crate) const ROM_TABLE_PTR: usize = 0x48;
 const VBIOS_DATE_OFFSET: usize = 0x50;
+const CHECKSUM_OFFSET: usize = 0x21;
 
 #[derive(Debug, Clone)]
-pub struct VbiosParser(Vec<u8>);
+pub struct VbiosParser(pub(crate) Vec<u8>);
 
 impl VbiosParser {
     pub fn new(v: Vec<u8>) -> Self {
@@ -42,6 +42,32 @@ impl VbiosParser {
         self.length() == self.0.len()
     }
 
+    /// Byte sum over `length()` bytes, `None` if the image is shorter than that
+    pub fn checksum(&self) -> Option<u8> {
+        let length = self.length();
+        if length == 0 { return None }
+
+        let image = self.0.get(..length)?;
+
+        Some(image.iter().fold(0u8, |sum, v| sum.wrapping_add(*v)))
+    }
+
+    /// The byte sum over the image size at offset 2 must be 0
+    pub fn verify_checksum(&self) -> bool {
+        self.checksum() == Some(0)
+    }
+
+    /// Returns the image with the checksum byte at offset 0x21 corrected
+    pub fn fix_checksum(&self) -> Option<Vec<u8>> {
+        let sum = self.checksum()?;
+        let mut image = self.0.clone();
+        let checksum = image.get_mut(CHECKSUM_OFFSET)?;
+
+        *checksum = checksum.wrapping_sub(sum);
+
+        Some(image)
+    }
+
     pub fn get_vbios_name(&self) -> Option<String> {
         const OFFSET_TO_GET_ATOMBIOS_NUMBER_OF_STRINGS: usize = 0x2F;
         const OFFSET_TO_GET_ATOMBIOS_STRING_START: usize = 0x6E;
@@ -96,26 +122,17 @@ impl VbiosParser {
         Some(date.to_vec())
     }
 
-    fn to_struct<T>(bin: &[u8], size: usize) -> T {
-        unsafe {
-            let mut s = MaybeUninit::<T>::zeroed();
-
-            ptr::copy_nonoverlapping(
-                bin.as_ptr(),
-                s.as_mut_ptr() as *mut u8,
-                size,
-            );
-
-            s.assume_init()
-        }
-    }
-
     fn read_u16(&self, offset: usize) -> Option<u16> {
         self.0.get(offset..offset+2)
             .and_then(|r| r.try_into().ok())
             .map(|arr| u16::from_le_bytes(arr))
     }
 
+    /// Read `size_of::<T>()` bytes from `offset` regardless of `structuresize`
+    pub(crate) fn read_struct<T>(&self, offset: usize) -> Option<T> {
+        super::read_struct(&self.0, offset)
+    }
+
     fn get_size_from_header(&self, offset: usize) -> Option<usize> {
         let size = self.read_header(offset)?.structuresize as usize;
 
@@ -125,19 +142,14 @@ impl VbiosParser {
     pub fn read_header(&self, offset: usize) -> Option<atom_common_table_header> {
         if offset == 0 { return None }
 
-        let size = size_of::<atom_common_table_header>();
-        let range = offset..offset+size;
-
-        let h = self.0.get(range)?;
-
-        Some(Self::to_struct(h, size))
+        super::read_struct(&self.0, offset)
     }
 
-    fn read_table_unchecked_size<T>(&self, offset: usize) -> Option<T> {
+    /// The rest is zero-filled if `structuresize` is smaller than `T`
+    pub(crate) fn read_table_unchecked_size<T>(&self, offset: usize) -> Option<T> {
         let size = self.get_size_from_header(offset)?;
-        let t = self.0.get(offset..offset+size)?;
 
-        Some(Self::to_struct(t, size))
+        super::read_struct_with_size(&self.0, offset, size)
     }
 
     pub fn read_table<T>(&self, offset: usize) -> Option<T> {
@@ -145,9 +157,7 @@ impl VbiosParser {
 
         if size != size_of::<T>() { return None }
 
-        let t = self.0.get(offset..offset+size)?;
-
-        Some(Self::to_struct(t, size))
+        super::read_struct(&self.0, offset)
     }
 
     pub fn get_atom_rom_header(&self) -> Option<atom_rom_header_v2_2> {
@@ -210,3 +220,21 @@ impl VbiosParser {
         PPTable::decode(bytes).ok()
     }
 }
+
+#[test]
+fn test_fix_checksum() {
+    let mut image = vec![0u8; 1024];
+
+    image[..2].copy_from_slice(VALID_VBIOS);
+    image[2] = 2;
+    image[0x100] = 0x12;
+
+    let parser = VbiosParser::new(image);
+
+    assert!(!parser.verify_checksum());
+
+    let parser = VbiosParser::new(parser.fix_checksum().unwrap());
+
+    assert!(parser.verify_checksum());
+    assert_eq!(parser.0[0x100], 0x12);
+}
@@ -0,0 +1,347 @@
+use std::fmt;
+use super::vbios_parser::VbiosParser;
+
+// ref: PCI Firmware Specification 3.x, 5.1 PCI Expansion ROM
+// ref: UEFI Specification, EFI PCI Expansion ROM Header
+
+const ROM_SIGNATURE: [u8; 2] = [0x55, 0xAA];
+const PCIR_PTR: usize = 0x18;
+const PCIR_SIGNATURE: &[u8; 4] = b"PCIR";
+const EFI_SIGNATURE: u32 = 0x0EF1;
+const IMAGE_UNIT: usize = 512;
+const LAST_IMAGE_INDICATOR: u8 = 1 << 7;
+
+impl VbiosParser {
+    /// Images in the PCI expansion ROM, the first one is the legacy (ATOMBIOS) image
+    pub fn pci_rom_images(&self) -> Vec<PciRomImage<'_>> {
+        PciRomImage::parse_all(&self.0)
+    }
+
+    /// UEFI GOP driver (EFI boot service driver image)
+    pub fn get_gop_image(&self) -> Option<PciRomImage<'_>> {
+        self.pci_rom_images().into_iter().find(|image| image.is_gop())
+    }
+}
+
+/// PCI Data Structure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PciDataStructure {
+    pub vendor_id: u16,
+    pub device_id: u16,
+    pub device_list_offset: u16,
+    pub length: u16,
+    pub revision: u8,
+    pub class_code: [u8; 3],
+    /// bytes
+    pub image_length: usize,
+    pub code_revision: u16,
+    pub code_type: PciRomCodeType,
+    pub last_image: bool,
+    /// bytes
+    pub max_runtime_image_length: usize,
+}
+
+impl PciDataStructure {
+    fn parse(bin: &[u8]) -> Option<Self> {
+        if bin.get(0..4)? != PCIR_SIGNATURE { return None }
+
+        Some(Self {
+            vendor_id: read_u16(bin, 0x4)?,
+            device_id: read_u16(bin, 0x6)?,
+            device_list_offset: read_u16(bin, 0x8)?,
+            length: read_u16(bin, 0xA)?,
+            revision: *bin.get(0xC)?,
+            class_code: bin.get(0xD..0x10)?.try_into().ok()?,
+            image_length: read_u16(bin, 0x10)? as usize * IMAGE_UNIT,
+            code_revision: read_u16(bin, 0x12)?,
+            code_type: PciRomCodeType::from(*bin.get(0x14)?),
+            last_image: (*bin.get(0x15)? & LAST_IMAGE_INDICATOR) != 0,
+            max_runtime_image_length: read_u16(bin, 0x16).unwrap_or(0) as usize * IMAGE_UNIT,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PciRomCodeType {
+    X86,
+    OpenFirmware,
+    HpPaRisc,
+    Efi,
+    Unknown(u8),
+}
+
+impl From<u8> for PciRomCodeType {
+    fn from(value: u8) -> Self {
+        match value {
+            0x0 => Self::X86,
+            0x1 => Self::OpenFirmware,
+            0x2 => Self::HpPaRisc,
+            0x3 => Self::Efi,
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+impl fmt::Display for PciRomCodeType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// EFI PCI Expansion ROM Header
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EfiRomHeader {
+    /// bytes
+    pub initialization_size: usize,
+    pub subsystem: EfiSubsystem,
+    pub machine_type: EfiMachineType,
+    pub compression_type: EfiCompressionType,
+    /// Offset of the PE image from the start of the ROM image
+    pub efi_image_offset: u16,
+}
+
+impl EfiRomHeader {
+    fn parse(bin: &[u8]) -> Option<Self> {
+        if read_u32(bin, 0x4)? != EFI_SIGNATURE { return None }
+
+        Some(Self {
+            initialization_size: read_u16(bin, 0x2)? as usize * IMAGE_UNIT,
+            subsystem: EfiSubsystem::from(read_u16(bin, 0x8)?),
+            machine_type: EfiMachineType::from(read_u16(bin, 0xA)?),
+            compression_type: EfiCompressionType::from(read_u16(bin, 0xC)?),
+            efi_image_offset: read_u16(bin, 0x16)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EfiSubsystem {
+    Application,
+    BootServiceDriver,
+    RuntimeDriver,
+    Unknown(u16),
+}
+
+impl From<u16> for EfiSubsystem {
+    fn from(value: u16) -> Self {
+        match value {
+            10 => Self::Application,
+            11 => Self::BootServiceDriver,
+            12 => Self::RuntimeDriver,
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+impl fmt::Display for EfiSubsystem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// `IMAGE_FILE_MACHINE_*`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EfiMachineType {
+    IA32,
+    IA64,
+    EBC,
+    X64,
+    ARMTHUMB_MIXED,
+    AARCH64,
+    RISCV64,
+    LOONGARCH64,
+    Unknown(u16),
+}
+
+impl From<u16> for EfiMachineType {
+    fn from(value: u16) -> Self {
+        match value {
+            0x014C => Self::IA32,
+            0x0200 => Self::IA64,
+            0x0EBC => Self::EBC,
+            0x8664 => Self::X64,
+            0x01C2 => Self::ARMTHUMB_MIXED,
+            0xAA64 => Self::AARCH64,
+            0x5064 => Self::RISCV64,
+            0x6264 => Self::LOONGARCH64,
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+impl fmt::Display for EfiMachineType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EfiCompressionType {
+    Uncompressed,
+    /// EFI compression algorithm (UEFI Specification, Compression Algorithm Specification)
+    Compressed,
+    Unknown(u16),
+}
+
+impl From<u16> for EfiCompressionType {
+    fn from(value: u16) -> Self {
+        match value {
+            0 => Self::Uncompressed,
+            1 => Self::Compressed,
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+impl fmt::Display for EfiCompressionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// An image in the PCI expansion ROM
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PciRomImage<'a> {
+    /// Offset of the image in the ROM
+    pub offset: usize,
+    pub pcir: PciDataStructure,
+    /// `Some` if `code_type` is EFI
+    pub efi: Option<EfiRomHeader>,
+    /// `image_length` bytes from `offset`.
+    /// Truncated if the ROM ends before that.
+    pub bytes: &'a [u8],
+}
+
+impl<'a> PciRomImage<'a> {
+    /// Images are walked until the last image indicator, the end of the ROM,
+    /// or an image without `0x55AA` or a PCI Data Structure
+    pub fn parse_all(rom: &'a [u8]) -> Vec<Self> {
+        let mut images = Vec::new();
+        let mut offset = 0usize;
+
+        while let Some(image) = Self::parse(rom, offset) {
+            let last = image.pcir.last_image;
+            let len = image.pcir.image_length;
+
+            images.push(image);
+
+            if last || len == 0 { break }
+
+            offset += len;
+        }
+
+        images
+    }
+
+    fn parse(rom: &'a [u8], offset: usize) -> Option<Self> {
+        let bin = rom.get(offset..)?;
+
+        if bin.get(0..2)? != ROM_SIGNATURE { return None }
+
+        let pcir_offset = read_u16(bin, PCIR_PTR)? as usize;
+        let pcir = PciDataStructure::parse(bin.get(pcir_offset..)?)?;
+        let efi = if pcir.code_type == PciRomCodeType::Efi {
+            EfiRomHeader::parse(bin)
+        } else {
+            None
+        };
+        let bytes = bin.get(..pcir.image_length).unwrap_or(bin);
+
+        Some(Self { offset, pcir, efi, bytes })
+    }
+
+    /// EFI boot service driver, as the UEFI GOP driver of AMD GPUs
+    pub fn is_gop(&self) -> bool {
+        self.efi.is_some_and(|efi| efi.subsystem == EfiSubsystem::BootServiceDriver)
+    }
+
+    /// PE image of the EFI driver, still compressed if `compression_type` is `Compressed`
+    pub fn efi_image(&self) -> Option<&'a [u8]> {
+        let efi = self.efi?;
+
+        self.bytes.get(efi.efi_image_offset as usize..)
+    }
+}
+
+impl fmt::Display for PciRomImage<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:#08x}: {:04X}:{:04X}, code type: {}, revision: {:#x}, length: {} bytes{}",
+            self.offset,
+            self.pcir.vendor_id,
+            self.pcir.device_id,
+            self.pcir.code_type,
+            self.pcir.code_revision,
+            self.pcir.image_length,
+            if self.pcir.last_image { ", last image" } else { "" },
+        )?;
+
+        if let Some(efi) = &self.efi {
+            write!(
+                f,
+                ", EFI {} ({}, {})",
+                efi.subsystem,
+                efi.machine_type,
+                efi.compression_type,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+fn read_u16(bin: &[u8], offset: usize) -> Option<u16> {
+    bin.get(offset..offset+2).map(|b| u16::from_le_bytes([b[0], b[1]]))
+}
+
+fn read_u32(bin: &[u8], offset: usize) -> Option<u32> {
+    bin.get(offset..offset+4).map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+#[test]
+fn test_pci_rom_images() {
+    fn image(code_type: u8, last: bool, len_512: u16) -> Vec<u8> {
+        let mut bin = vec![0u8; len_512 as usize * IMAGE_UNIT];
+        let pcir = 0x40usize;
+
+        bin[0..2].copy_from_slice(&ROM_SIGNATURE);
+        bin[PCIR_PTR..PCIR_PTR+2].copy_from_slice(&(pcir as u16).to_le_bytes());
+        bin[pcir..pcir+4].copy_from_slice(PCIR_SIGNATURE);
+        bin[pcir+0x4..pcir+0x6].copy_from_slice(&0x1002u16.to_le_bytes());
+        bin[pcir+0x6..pcir+0x8].copy_from_slice(&0x73BFu16.to_le_bytes());
+        bin[pcir+0x10..pcir+0x12].copy_from_slice(&len_512.to_le_bytes());
+        bin[pcir+0x14] = code_type;
+        bin[pcir+0x15] = if last { LAST_IMAGE_INDICATOR } else { 0 };
+
+        if code_type == 0x3 {
+            bin[0x4..0x8].copy_from_slice(&EFI_SIGNATURE.to_le_bytes());
+            bin[0x8..0xA].copy_from_slice(&11u16.to_le_bytes());
+            bin[0xA..0xC].copy_from_slice(&0x8664u16.to_le_bytes());
+            bin[0x16..0x18].copy_from_slice(&0x60u16.to_le_bytes());
+        }
+
+        bin
+    }
+
+    let mut rom = image(0x0, false, 2);
+    rom.extend(image(0x3, true, 1));
+    /* padding after the last image */
+    rom.extend([0xFFu8; 64]);
+
+    let images = PciRomImage::parse_all(&rom);
+
+    assert_eq!(images.len(), 2);
+    assert_eq!(images[0].pcir.code_type, PciRomCodeType::X86);
+    assert_eq!(images[0].pcir.device_id, 0x73BF);
+    assert!(!images[0].is_gop());
+    assert_eq!(images[1].offset, 1024);
+    assert!(images[1].pcir.last_image);
+    assert!(images[1].is_gop());
+
+    let efi = images[1].efi.unwrap();
+
+    assert_eq!(efi.machine_type, EfiMachineType::X64);
+    assert_eq!(efi.compression_type, EfiCompressionType::Uncompressed);
+    assert_eq!(images[1].efi_image().unwrap().len(), IMAGE_UNIT - 0x60);
+}
@@ -0,0 +1,406 @@
+use crate::bindings::{
+    atom_common_table_header,
+    atom_master_data_table_v2_1,
+    atom_smu_info_v3_1,
+    atom_smu_info_v3_2,
+    atom_smu_info_v3_3,
+    atom_smu_info_v3_5,
+    atom_smu_info_v3_6,
+    atom_smu_info_v4_0,
+    atom_smc_dpm_info_v4_1,
+    atom_smc_dpm_info_v4_3,
+    atom_smc_dpm_info_v4_4,
+    atom_smc_dpm_info_v4_5,
+    atom_smc_dpm_info_v4_6,
+    atom_smc_dpm_info_v4_7,
+    atom_smc_dpm_info_v4_9,
+    atom_smc_dpm_info_v4_10,
+};
+use super::vbios_parser::VbiosParser;
+
+// ref: drivers/gpu/drm/amd/include/atomfirmware.h
+
+/// `Some(expr)` for the listed versions, `None` for the others
+macro_rules! match_ver {
+    ($self: expr, $t: ident, $( [$($ver: ident),*] => $e: expr ),* $(,)?) => {
+        match $self {
+            $($(Self::$ver($t) => Some($e),)*)*
+            _ => None,
+        }
+    };
+}
+
+impl VbiosParser {
+    pub fn get_smu_info(
+        &self,
+        data_table: &atom_master_data_table_v2_1,
+    ) -> Option<VbiosSmuInfo> {
+        let offset = data_table.listOfdatatables.smu_info as usize;
+        let header = self.read_header(offset)?;
+
+        let info = match (header.format_revision, header.content_revision) {
+            (3, 1) => VbiosSmuInfo::V3_1(self.read_struct(offset)?),
+            (3, 2) => VbiosSmuInfo::V3_2(self.read_struct(offset)?),
+            (3, 3) => VbiosSmuInfo::V3_3(self.read_struct(offset)?),
+            (3, 5) => VbiosSmuInfo::V3_5(self.read_struct(offset)?),
+            (3, 6) => VbiosSmuInfo::V3_6(self.read_struct(offset)?),
+            (4, 0) => VbiosSmuInfo::V4_0(self.read_struct(offset)?),
+            _ => VbiosSmuInfo::Unknown(header),
+        };
+
+        Some(info)
+    }
+
+    pub fn get_smc_dpm_info(
+        &self,
+        data_table: &atom_master_data_table_v2_1,
+    ) -> Option<VbiosSmcDpmInfo> {
+        let offset = data_table.listOfdatatables.smc_dpm_info as usize;
+        let header = self.read_header(offset)?;
+
+        let info = match (header.format_revision, header.content_revision) {
+            (4, 1) => VbiosSmcDpmInfo::V4_1(self.read_struct(offset)?),
+            (4, 3) => VbiosSmcDpmInfo::V4_3(self.read_struct(offset)?),
+            (4, 4) => VbiosSmcDpmInfo::V4_4(self.read_struct(offset)?),
+            (4, 5) => VbiosSmcDpmInfo::V4_5(self.read_struct(offset)?),
+            (4, 6) => VbiosSmcDpmInfo::V4_6(self.read_struct(offset)?),
+            (4, 7) => VbiosSmcDpmInfo::V4_7(self.read_struct(offset)?),
+            (4, 9) => VbiosSmcDpmInfo::V4_9(self.read_struct(offset)?),
+            (4, 10) => VbiosSmcDpmInfo::V4_10(self.read_struct(offset)?),
+            _ => VbiosSmcDpmInfo::Unknown(header),
+        };
+
+        Some(info)
+    }
+}
+
+/// GPIO pin and its polarity
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VbiosGpio {
+    pub pin: u8,
+    pub polarity: u8,
+}
+
+impl VbiosGpio {
+    fn new(pin: u8, polarity: u8) -> Self {
+        Self { pin, polarity }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum VbiosSmuInfo {
+    V3_1(atom_smu_info_v3_1),
+    V3_2(atom_smu_info_v3_2),
+    V3_3(atom_smu_info_v3_3),
+    V3_5(atom_smu_info_v3_5),
+    V3_6(atom_smu_info_v3_6),
+    V4_0(atom_smu_info_v4_0),
+    Unknown(atom_common_table_header),
+}
+
+impl VbiosSmuInfo {
+    pub fn header(&self) -> atom_common_table_header {
+        match self {
+            Self::V3_1(t) => t.table_header,
+            Self::V3_2(t) => t.table_header,
+            Self::V3_3(t) => t.table_header,
+            Self::V3_5(t) => t.table_header,
+            Self::V3_6(t) => t.table_header,
+            Self::V4_0(t) => t.table_header,
+            Self::Unknown(h) => *h,
+        }
+    }
+
+    /// 10 kHz
+    pub fn core_refclk(&self) -> Option<u32> {
+        match_ver!(self, t, [V3_1, V3_2, V3_3, V3_5, V3_6, V4_0] => t.core_refclk_10khz)
+    }
+
+    pub fn bootup(&self) -> VbiosSmuBootup {
+        VbiosSmuBootup {
+            smnclk: match_ver!(self, t, [V3_2, V3_3, V3_5, V3_6, V4_0] => t.bootup_smnclk_10khz),
+            socclk: match_ver!(self, t, [V3_2, V3_3, V3_5, V3_6, V4_0] => t.bootup_socclk_10khz),
+            mp0clk: match_ver!(self, t, [V3_2, V3_3, V3_5, V3_6, V4_0] => t.bootup_mp0clk_10khz),
+            mp1clk: match_ver!(self, t, [V3_2, V3_3, V3_5, V3_6, V4_0] => t.bootup_mp1clk_10khz),
+            lclk: match_ver!(self, t, [V3_2, V3_3, V3_5, V3_6, V4_0] => t.bootup_lclk_10khz),
+            dcefclk: match_ver!(self, t, [V3_2, V3_3, V3_5, V4_0] => t.bootup_dcefclk_10khz),
+            fclk: match_ver!(self, t, [V3_3, V3_5, V3_6, V4_0] => t.bootup_fclk_10khz),
+            waflclk: match_ver!(self, t, [V3_3, V3_5, V3_6, V4_0] => t.bootup_waflclk_10khz),
+            dprefclk: match_ver!(self, t, [V3_5, V4_0] => t.bootup_dprefclk_10khz),
+            usbclk: match_ver!(self, t, [V3_5, V4_0] => t.bootup_usbclk_10khz),
+            dtbclk: match_ver!(self, t, [V3_5, V4_0] => t.bootup_dtbclk_10khz),
+            vclk: match_ver!(self, t, [V3_6] => t.bootup_vclk_10khz, [V4_0] => t.bootup_vclk0_10khz),
+            dclk: match_ver!(self, t, [V3_6] => t.bootup_dclk_10khz, [V4_0] => t.bootup_dclk0_10khz),
+            vddusr_mv: match_ver!(self, t, [V4_0] => t.bootup_vddusr_mv),
+        }
+    }
+
+    pub fn gpio(&self) -> VbiosSmuGpio {
+        VbiosSmuGpio {
+            ac_dc: match_ver!(self, t, [V3_1, V3_2, V3_3] =>
+                VbiosGpio::new(t.ac_dc_gpio_bit, t.ac_dc_polarity)),
+            vr0hot: match_ver!(self, t, [V3_1, V3_2, V3_3] =>
+                VbiosGpio::new(t.vr0hot_gpio_bit, t.vr0hot_polarity)),
+            vr1hot: match_ver!(self, t, [V3_1, V3_2, V3_3] =>
+                VbiosGpio::new(t.vr1hot_gpio_bit, t.vr1hot_polarity)),
+            fw_ctf: match_ver!(self, t, [V3_1, V3_2, V3_3] =>
+                VbiosGpio::new(t.fw_ctf_gpio_bit, t.fw_ctf_polarity)),
+            pcc: match_ver!(self, t, [V3_2, V3_3, V3_5, V3_6, V4_0] =>
+                VbiosGpio::new(t.pcc_gpio_bit, t.pcc_gpio_polarity)),
+        }
+    }
+}
+
+/// Boot-up clocks (10 kHz) and voltage from `smu_info`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VbiosSmuBootup {
+    pub smnclk: Option<u32>,
+    pub socclk: Option<u32>,
+    pub mp0clk: Option<u32>,
+    pub mp1clk: Option<u32>,
+    pub lclk: Option<u32>,
+    pub dcefclk: Option<u32>,
+    pub fclk: Option<u32>,
+    pub waflclk: Option<u32>,
+    pub dprefclk: Option<u32>,
+    pub usbclk: Option<u32>,
+    pub dtbclk: Option<u32>,
+    pub vclk: Option<u32>,
+    pub dclk: Option<u32>,
+    pub vddusr_mv: Option<u16>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VbiosSmuGpio {
+    pub ac_dc: Option<VbiosGpio>,
+    pub vr0hot: Option<VbiosGpio>,
+    pub vr1hot: Option<VbiosGpio>,
+    pub fw_ctf: Option<VbiosGpio>,
+    pub pcc: Option<VbiosGpio>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum VbiosSmcDpmInfo {
+    V4_1(atom_smc_dpm_info_v4_1),
+    V4_3(atom_smc_dpm_info_v4_3),
+    V4_4(atom_smc_dpm_info_v4_4),
+    V4_5(atom_smc_dpm_info_v4_5),
+    V4_6(atom_smc_dpm_info_v4_6),
+    V4_7(atom_smc_dpm_info_v4_7),
+    V4_9(atom_smc_dpm_info_v4_9),
+    V4_10(atom_smc_dpm_info_v4_10),
+    Unknown(atom_common_table_header),
+}
+
+impl VbiosSmcDpmInfo {
+    pub fn header(&self) -> atom_common_table_header {
+        match self {
+            Self::V4_1(t) => t.table_header,
+            Self::V4_3(t) => t.table_header,
+            Self::V4_4(t) => t.table_header,
+            Self::V4_5(t) => t.table_header,
+            Self::V4_6(t) => t.table_header,
+            Self::V4_7(t) => t.table_header,
+            Self::V4_9(t) => t.table_header,
+            Self::V4_10(t) => t.table_header,
+            Self::Unknown(h) => *h,
+        }
+    }
+
+    pub fn board_params(&self) -> VbiosSmcBoardParams {
+        VbiosSmcBoardParams {
+            max_voltage_step_gfx: match_ver!(self, t,
+                [V4_1, V4_3, V4_4, V4_6] => t.maxvoltagestepgfx,
+                [V4_5, V4_7] => t.MaxVoltageStepGfx,
+            ),
+            max_voltage_step_soc: match_ver!(self, t,
+                [V4_1, V4_3, V4_4, V4_6] => t.maxvoltagestepsoc,
+                [V4_5, V4_7] => t.MaxVoltageStepSoc,
+            ),
+            vdd_gfx_vr_mapping: match_ver!(self, t,
+                [V4_1, V4_3, V4_4, V4_6] => t.vddgfxvrmapping,
+                [V4_5, V4_7, V4_9] => t.VddGfxVrMapping,
+            ),
+            vdd_soc_vr_mapping: match_ver!(self, t,
+                [V4_1, V4_3, V4_4, V4_6] => t.vddsocvrmapping,
+                [V4_5, V4_7, V4_9] => t.VddSocVrMapping,
+            ),
+            gfx_max_current: match_ver!(self, t,
+                [V4_1, V4_3, V4_4, V4_6] => t.gfxmaxcurrent,
+                [V4_5, V4_7, V4_9, V4_10] => t.GfxMaxCurrent,
+            ),
+            gfx_offset: match_ver!(self, t,
+                [V4_1, V4_3, V4_4, V4_6] => t.gfxoffset,
+                [V4_5, V4_7, V4_9, V4_10] => t.GfxOffset,
+            ),
+            soc_max_current: match_ver!(self, t,
+                [V4_1, V4_3, V4_4, V4_6] => t.socmaxcurrent,
+                [V4_5, V4_7, V4_9, V4_10] => t.SocMaxCurrent,
+            ),
+            soc_offset: match_ver!(self, t,
+                [V4_1, V4_3, V4_4, V4_6] => t.socoffset,
+                [V4_5, V4_7, V4_9, V4_10] => t.SocOffset,
+            ),
+            total_board_power: match_ver!(self, t,
+                [V4_6] => t.totalboardpower,
+                [V4_5, V4_7, V4_9] => t.TotalBoardPower,
+            ),
+            ac_dc: match_ver!(self, t,
+                [V4_1, V4_3, V4_4] => VbiosGpio::new(t.acdcgpio, t.acdcpolarity),
+                [V4_5, V4_7, V4_9] => VbiosGpio::new(t.AcDcGpio, t.AcDcPolarity),
+            ),
+            vr0hot: match_ver!(self, t,
+                [V4_1, V4_3, V4_4, V4_6] => VbiosGpio::new(t.vr0hotgpio, t.vr0hotpolarity),
+                [V4_5, V4_7, V4_9, V4_10] => VbiosGpio::new(t.VR0HotGpio, t.VR0HotPolarity),
+            ),
+            vr1hot: match_ver!(self, t,
+                [V4_1, V4_3, V4_4, V4_6] => VbiosGpio::new(t.vr1hotgpio, t.vr1hotpolarity),
+                [V4_5, V4_7, V4_9, V4_10] => VbiosGpio::new(t.VR1HotGpio, t.VR1HotPolarity),
+            ),
+            uclk_spread: match_ver!(self, t,
+                [V4_1, V4_3, V4_4, V4_6] => VbiosSpreadSpectrum::new(
+                    t.uclkspreadenabled,
+                    t.uclkspreadpercent,
+                    t.uclkspreadfreq,
+                ),
+                [V4_5, V4_7, V4_9, V4_10] => VbiosSpreadSpectrum::new(
+                    t.UclkSpreadEnabled,
+                    t.UclkSpreadPercent,
+                    t.UclkSpreadFreq,
+                ),
+            ),
+            fclk_spread: match_ver!(self, t,
+                [V4_3, V4_4, V4_6] => VbiosSpreadSpectrum::new(
+                    t.fclkspreadenabled,
+                    t.fclkspreadpercent,
+                    t.fclkspreadfreq,
+                ),
+                [V4_9, V4_10] => VbiosSpreadSpectrum::new(
+                    t.FclkSpreadEnabled,
+                    t.FclkSpreadPercent,
+                    t.FclkSpreadFreq,
+                ),
+            ),
+        }
+    }
+}
+
+/// Board-level parameters from `smc_dpm_info`, passed to the SMU as a part of PPTable
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VbiosSmcBoardParams {
+    pub max_voltage_step_gfx: Option<u16>,
+    pub max_voltage_step_soc: Option<u16>,
+    pub vdd_gfx_vr_mapping: Option<u8>,
+    pub vdd_soc_vr_mapping: Option<u8>,
+    pub gfx_max_current: Option<u16>,
+    pub gfx_offset: Option<u8>,
+    pub soc_max_current: Option<u16>,
+    pub soc_offset: Option<u8>,
+    pub total_board_power: Option<u16>,
+    pub ac_dc: Option<VbiosGpio>,
+    pub vr0hot: Option<VbiosGpio>,
+    pub vr1hot: Option<VbiosGpio>,
+    pub uclk_spread: Option<VbiosSpreadSpectrum>,
+    pub fclk_spread: Option<VbiosSpreadSpectrum>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VbiosSpreadSpectrum {
+    pub enabled: bool,
+    pub percent: u8,
+    pub freq: u16,
+}
+
+impl VbiosSpreadSpectrum {
+    fn new(enabled: u8, percent: u8, freq: u16) -> Self {
+        Self { enabled: enabled != 0, percent, freq }
+    }
+}
+
+#[cfg(test)]
+fn table_bin(offset: usize, size: usize, rev: (u8, u8)) -> Vec<u8> {
+    let mut bin = vec![0u8; offset + size];
+    bin[offset..offset+2].copy_from_slice(&(size as u16).to_le_bytes());
+    bin[offset+2] = rev.0;
+    bin[offset+3] = rev.1;
+
+    bin
+}
+
+#[test]
+fn test_smu_info_v3_6() {
+    use core::mem::{offset_of, size_of};
+
+    const OFFSET: usize = 0x10;
+    let mut bin = table_bin(OFFSET, size_of::<atom_smu_info_v3_6>(), (3, 6));
+    let t = &mut bin[OFFSET..];
+    t[offset_of!(atom_smu_info_v3_6, bootup_socclk_10khz)..][..4].copy_from_slice(&60_000u32.to_le_bytes());
+    t[offset_of!(atom_smu_info_v3_6, bootup_fclk_10khz)..][..4].copy_from_slice(&40_000u32.to_le_bytes());
+    t[offset_of!(atom_smu_info_v3_6, bootup_vclk_10khz)..][..4].copy_from_slice(&70_000u32.to_le_bytes());
+    t[offset_of!(atom_smu_info_v3_6, pcc_gpio_bit)] = 5;
+    t[offset_of!(atom_smu_info_v3_6, pcc_gpio_polarity)] = 1;
+
+    let mut data_table: atom_master_data_table_v2_1 = unsafe { core::mem::zeroed() };
+    data_table.listOfdatatables.smu_info = OFFSET as u16;
+
+    let smu_info = VbiosParser::new(bin).get_smu_info(&data_table).unwrap();
+    let bootup = smu_info.bootup();
+    let gpio = smu_info.gpio();
+
+    assert!(matches!(smu_info, VbiosSmuInfo::V3_6(_)));
+    assert_eq!(bootup.socclk, Some(60_000));
+    assert_eq!(bootup.fclk, Some(40_000));
+    assert_eq!(bootup.vclk, Some(70_000));
+    /* not in v3.6 */
+    assert_eq!(bootup.dcefclk, None);
+    assert_eq!(bootup.dprefclk, None);
+    assert_eq!(gpio.pcc, Some(VbiosGpio { pin: 5, polarity: 1 }));
+    assert_eq!(gpio.ac_dc, None);
+}
+
+#[test]
+fn test_smc_dpm_info_v4_9() {
+    use core::mem::{offset_of, size_of};
+
+    const OFFSET: usize = 0x10;
+    let mut bin = table_bin(OFFSET, size_of::<atom_smc_dpm_info_v4_9>(), (4, 9));
+    let t = &mut bin[OFFSET..];
+    t[offset_of!(atom_smc_dpm_info_v4_9, GfxMaxCurrent)..][..2].copy_from_slice(&300u16.to_le_bytes());
+    t[offset_of!(atom_smc_dpm_info_v4_9, TotalBoardPower)..][..2].copy_from_slice(&250u16.to_le_bytes());
+    t[offset_of!(atom_smc_dpm_info_v4_9, VR0HotGpio)] = 3;
+    t[offset_of!(atom_smc_dpm_info_v4_9, FclkSpreadEnabled)] = 1;
+    t[offset_of!(atom_smc_dpm_info_v4_9, FclkSpreadPercent)] = 2;
+    t[offset_of!(atom_smc_dpm_info_v4_9, FclkSpreadFreq)..][..2].copy_from_slice(&33u16.to_le_bytes());
+
+    let mut data_table: atom_master_data_table_v2_1 = unsafe { core::mem::zeroed() };
+    data_table.listOfdatatables.smc_dpm_info = OFFSET as u16;
+
+    let dpm_info = VbiosParser::new(bin).get_smc_dpm_info(&data_table).unwrap();
+    let params = dpm_info.board_params();
+
+    assert!(matches!(dpm_info, VbiosSmcDpmInfo::V4_9(_)));
+    assert_eq!(params.gfx_max_current, Some(300));
+    assert_eq!(params.total_board_power, Some(250));
+    assert_eq!(params.vr0hot, Some(VbiosGpio { pin: 3, polarity: 0 }));
+    assert_eq!(params.fclk_spread, Some(VbiosSpreadSpectrum { enabled: true, percent: 2, freq: 33 }));
+    /* not in v4.9 */
+    assert_eq!(params.max_voltage_step_gfx, None);
+}
+
+#[test]
+fn test_smu_info_unknown_revision() {
+    const OFFSET: usize = 0x10;
+    let bin = table_bin(OFFSET, 0x100, (3, 4));
+
+    let mut data_table: atom_master_data_table_v2_1 = unsafe { core::mem::zeroed() };
+    data_table.listOfdatatables.smu_info = OFFSET as u16;
+
+    let smu_info = VbiosParser::new(bin).get_smu_info(&data_table).unwrap();
+    let header = smu_info.header();
+
+    assert!(matches!(smu_info, VbiosSmuInfo::Unknown(_)));
+    assert_eq!((header.format_revision, header.content_revision), (3, 4));
+    assert_eq!(smu_info.core_refclk(), None);
+    assert_eq!(smu_info.bootup(), VbiosSmuBootup::default());
+    assert_eq!(smu_info.gpio(), VbiosSmuGpio::default());
+}
@@ -0,0 +1,219 @@
+use core::mem::size_of;
+use crate::bindings::{atom_common_table_header, atom_rom_header_v2_2};
+use super::vbios_parser::VbiosParser;
+use super::{diff_bytes, diff_fields, FieldDiff};
+
+// ref: drivers/gpu/drm/amd/include/atomfirmware.h
+
+/// Field names of `atom_master_list_of_data_tables_v2_1`
+pub const ATOM_DATA_TABLE_NAMES: [&str; 35] = [
+    "utilitypipeline", "multimedia_info", "smc_dpm_info", "sw_datatable3", "firmwareinfo",
+    "sw_datatable5", "lcd_info", "sw_datatable7", "smu_info", "sw_datatable9", "sw_datatable10",
+    "vram_usagebyfirmware", "gpio_pin_lut", "sw_datatable13", "gfx_info", "powerplayinfo",
+    "sw_datatable16", "sw_datatable17", "sw_datatable18", "sw_datatable19", "sw_datatable20",
+    "sw_datatable21", "displayobjectinfo", "indirectioaccess", "umc_info", "sw_datatable25",
+    "sw_datatable26", "dce_info", "vram_info", "sw_datatable29", "integratedsysteminfo",
+    "asic_profiling_info", "voltageobject_info", "sw_datatable33", "sw_datatable34",
+];
+
+/// Field names of `atom_master_list_of_command_functions_v2_1`
+pub const ATOM_COMMAND_TABLE_NAMES: [&str; 81] = [
+    "asic_init", "cmd_function1", "cmd_function2", "cmd_function3", "digxencodercontrol",
+    "cmd_function5", "cmd_function6", "cmd_function7", "cmd_function8", "cmd_function9",
+    "setengineclock", "setmemoryclock", "setpixelclock", "enabledisppowergating", "cmd_function14",
+    "cmd_function15", "cmd_function16", "cmd_function17", "cmd_function18", "cmd_function19",
+    "cmd_function20", "cmd_function21", "cmd_function22", "cmd_function23", "cmd_function24",
+    "cmd_function25", "cmd_function26", "cmd_function27", "cmd_function28", "cmd_function29",
+    "cmd_function30", "cmd_function31", "cmd_function32", "cmd_function33", "blankcrtc",
+    "enablecrtc", "cmd_function36", "cmd_function37", "cmd_function38", "cmd_function39",
+    "cmd_function40", "getsmuclockinfo", "selectcrtc_source", "cmd_function43", "cmd_function44",
+    "cmd_function45", "setdceclock", "getmemoryclock", "getengineclock", "setcrtc_usingdtdtiming",
+    "externalencodercontrol", "cmd_function51", "cmd_function52", "cmd_function53",
+    "processi2cchanneltransaction", "cmd_function55", "cmd_function56", "cmd_function57",
+    "cmd_function58", "cmd_function59", "computegpuclockparam", "cmd_function61", "cmd_function62",
+    "dynamicmemorysettings", "memorytraining", "cmd_function65", "cmd_function66", "setvoltage",
+    "cmd_function68", "readefusevalue", "cmd_function70", "cmd_function71", "cmd_function72",
+    "cmd_function73", "cmd_function74", "cmd_function75", "dig1transmittercontrol",
+    "cmd_function77", "processauxchanneltransaction", "cmd_function79", "getvoltageinfo",
+];
+
+/// An entry of the master data table or the master command table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AtomTableEntry<'a> {
+    /// Index in the master list
+    pub index: usize,
+    /// `None` if the index is beyond the known list
+    pub name: Option<&'static str>,
+    pub offset: usize,
+    pub structuresize: u16,
+    pub format_revision: u8,
+    pub content_revision: u8,
+    /// `structuresize` bytes from `offset`, including the header.
+    /// Truncated if the image ends before that.
+    pub bytes: &'a [u8],
+}
+
+impl VbiosParser {
+    /// Non-zero entries of `atom_master_list_of_data_tables_v2_1`
+    pub fn data_tables(
+        &self,
+        rom_header: &atom_rom_header_v2_2,
+    ) -> impl Iterator<Item = AtomTableEntry<'_>> + '_ {
+        self.master_list_entries(rom_header.masterdatatable_offset as usize, &ATOM_DATA_TABLE_NAMES)
+    }
+
+    /// Non-zero entries of `atom_master_list_of_command_functions_v2_1`
+    pub fn command_tables(
+        &self,
+        rom_header: &atom_rom_header_v2_2,
+    ) -> impl Iterator<Item = AtomTableEntry<'_>> + '_ {
+        self.master_list_entries(rom_header.masterhwfunction_offset as usize, &ATOM_COMMAND_TABLE_NAMES)
+    }
+
+    /// The number of entries is taken from `structuresize` of the master table,
+    /// so entries newer than the bindings are also listed.
+    fn master_list_entries<'a>(
+        &'a self,
+        master_offset: usize,
+        names: &'static [&'static str],
+    ) -> impl Iterator<Item = AtomTableEntry<'a>> + 'a {
+        let header_size = size_of::<atom_common_table_header>();
+        let num = self.read_header(master_offset)
+            .map(|h| (h.structuresize as usize).saturating_sub(header_size) / size_of::<u16>())
+            .unwrap_or(0);
+
+        (0..num).filter_map(move |index| {
+            let offset = self.read_struct::<u16>(master_offset + header_size + index * 2)? as usize;
+            let header = self.read_header(offset)?;
+            let bytes = self.0.get(offset..)?;
+            let bytes = bytes.get(..header.structuresize as usize).unwrap_or(bytes);
+
+            Some(AtomTableEntry {
+                index,
+                name: names.get(index).copied(),
+                offset,
+                structuresize: header.structuresize,
+                format_revision: header.format_revision,
+                content_revision: header.content_revision,
+                bytes,
+            })
+        })
+    }
+}
+
+/* compared field by field in `diff_data_tables` */
+const DECODED_DATA_TABLES: &[&str] = &[
+    "firmwareinfo", "smc_dpm_info", "smu_info", "gfx_info", "powerplayinfo",
+    "displayobjectinfo", "vram_info", "integratedsysteminfo",
+];
+
+impl VbiosParser {
+    /// Compare the data tables with `other` (e.g. another revision of the VBIOS).
+    /// The tables with a decoder are compared field by field (see [diff_fields]),
+    /// the others by the bytes (see [diff_bytes]).
+    /// Paths start with the name in `ATOM_DATA_TABLE_NAMES`.
+    /// `None` if either image is not atomfirmware.
+    pub fn diff_data_tables(&self, other: &Self) -> Option<Vec<FieldDiff>> {
+        let (old_rom, new_rom) = (self.get_atom_rom_header()?, other.get_atom_rom_header()?);
+        let (old_dt, new_dt) = (self.get_atom_data_table(&old_rom)?, other.get_atom_data_table(&new_rom)?);
+        let mut diffs = Vec::new();
+
+        macro_rules! diff_decoded {
+            ($name:literal, $get:ident) => {
+                diffs.extend(
+                    diff_fields(&self.$get(&old_dt), &other.$get(&new_dt))
+                        .into_iter()
+                        .map(|d| d.with_prefix($name))
+                );
+            };
+        }
+
+        diff_decoded!("firmwareinfo", get_atom_firmware_info);
+        diff_decoded!("smc_dpm_info", get_smc_dpm_info);
+        diff_decoded!("smu_info", get_smu_info);
+        diff_decoded!("gfx_info", get_gfx_info);
+        diff_decoded!("powerplayinfo", get_powerplay_table);
+        diff_decoded!("displayobjectinfo", get_display_object_info);
+        diff_decoded!("vram_info", get_vram_info);
+        diff_decoded!("integratedsysteminfo", get_integrated_system_info);
+
+        let old_raw = self.raw_data_tables(&old_rom);
+        let new_raw = other.raw_data_tables(&new_rom);
+
+        for (name, old) in &old_raw {
+            let new = new_raw.iter().find(|(n, _)| n == name).map_or(&[][..], |(_, b)| b);
+
+            diffs.extend(diff_bytes(old, new).into_iter().map(|d| d.with_prefix(name)));
+        }
+
+        for (name, new) in new_raw.iter().filter(|(n, _)| !old_raw.iter().any(|(o, _)| o == n)) {
+            diffs.extend(diff_bytes(&[], new).into_iter().map(|d| d.with_prefix(name)));
+        }
+
+        Some(diffs)
+    }
+
+    /* the tables without a decoder, including the header */
+    fn raw_data_tables(&self, rom_header: &atom_rom_header_v2_2) -> Vec<(String, &[u8])> {
+        self.data_tables(rom_header)
+            .filter(|e| !e.name.is_some_and(|n| DECODED_DATA_TABLES.contains(&n)))
+            .map(|e| {
+                let name = e.name.map(|n| n.to_string()).unwrap_or_else(|| format!("datatable{}", e.index));
+
+                (name, e.bytes)
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn test_master_table_entries() {
+    const DATA_MASTER: usize = 0x100;
+    const CMD_MASTER: usize = 0x200;
+    /* one more entry than ATOM_COMMAND_TABLE_NAMES */
+    let num_cmd = ATOM_COMMAND_TABLE_NAMES.len() + 1;
+
+    let mut bin = vec![0u8; 0x500];
+    let mut put_table = |offset: usize, size: u16, rev: (u8, u8)| {
+        bin[offset..offset+2].copy_from_slice(&size.to_le_bytes());
+        bin[offset+2] = rev.0;
+        bin[offset+3] = rev.1;
+    };
+
+    put_table(DATA_MASTER, (4 + ATOM_DATA_TABLE_NAMES.len() * 2) as u16, (2, 1));
+    put_table(CMD_MASTER, (4 + num_cmd * 2) as u16, (2, 1));
+    put_table(0x400, 0x20, (4, 0)); // smu_info
+    put_table(0x420, 0x10, (2, 2)); // gfx_info
+    put_table(0x440, 0x30, (1, 2)); // asic_init
+    put_table(0x4F0, 0x40, (1, 1)); // beyond the known list, truncated by the image end
+
+    let mut put_entry = |master: usize, index: usize, offset: u16| {
+        bin[master+4+index*2..][..2].copy_from_slice(&offset.to_le_bytes());
+    };
+
+    put_entry(DATA_MASTER, 8, 0x400);
+    put_entry(DATA_MASTER, 14, 0x420);
+    put_entry(CMD_MASTER, 0, 0x440);
+    put_entry(CMD_MASTER, num_cmd - 1, 0x4F0);
+
+    let mut rom_header: atom_rom_header_v2_2 = unsafe { core::mem::zeroed() };
+    rom_header.masterdatatable_offset = DATA_MASTER as u16;
+    rom_header.masterhwfunction_offset = CMD_MASTER as u16;
+
+    let vbios = VbiosParser::new(bin);
+    let data: Vec<_> = vbios.data_tables(&rom_header).collect();
+    let cmd: Vec<_> = vbios.command_tables(&rom_header).collect();
+
+    assert_eq!(data.len(), 2);
+    assert_eq!((data[0].index, data[0].name, data[0].offset), (8, Some("smu_info"), 0x400));
+    assert_eq!((data[0].format_revision, data[0].content_revision), (4, 0));
+    assert_eq!(data[0].bytes.len(), 0x20);
+    assert_eq!((data[1].index, data[1].name), (14, Some("gfx_info")));
+    assert_eq!((data[1].format_revision, data[1].content_revision), (2, 2));
+
+    assert_eq!(cmd.len(), 2);
+    assert_eq!((cmd[0].index, cmd[0].name, cmd[0].structuresize), (0, Some("asic_init"), 0x30));
+    assert_eq!((cmd[1].index, cmd[1].name), (num_cmd - 1, None));
+    assert_eq!(cmd[1].structuresize, 0x40);
+    assert_eq!(cmd[1].bytes.len(), 0x10);
+}
@@ -0,0 +1,438 @@
+use core::mem::{offset_of, size_of};
+use core::ffi::c_char;
+use std::fmt;
+use crate::AMDGPU::VRAM_TYPE;
+use crate::bindings::{
+    atom_master_data_table_v2_1,
+    atom_vram_info_header_v2_3,
+    atom_vram_info_header_v2_4,
+    atom_vram_info_header_v2_5,
+    atom_vram_info_header_v2_6,
+    atom_vram_info_header_v3_0,
+    atom_vram_module_v9,
+    atom_vram_module_v10,
+    atom_vram_module_v11,
+    atom_vram_module_v3_0,
+    atom_gddr6_ac_timing_v2_5,
+    atom_dgpu_vram_type_ATOM_DGPU_VRAM_TYPE_GDDR5,
+    atom_dgpu_vram_type_ATOM_DGPU_VRAM_TYPE_HBM2,
+    atom_dgpu_vram_type_ATOM_DGPU_VRAM_TYPE_HBM2E,
+    atom_dgpu_vram_type_ATOM_DGPU_VRAM_TYPE_GDDR6,
+    atom_dgpu_vram_type_ATOM_DGPU_VRAM_TYPE_HBM3,
+};
+use super::vbios_parser::VbiosParser;
+
+// ref: drivers/gpu/drm/amd/amdgpu/amdgpu_atomfirmware.c, amdgpu_atomfirmware_get_vram_info
+
+impl VbiosParser {
+    pub fn get_vram_info(
+        &self,
+        data_table: &atom_master_data_table_v2_1,
+    ) -> Option<VbiosVramInfo> {
+        let offset = data_table.listOfdatatables.vram_info as usize;
+        let header = self.read_header(offset)?;
+        let (format_revision, content_revision) = (header.format_revision, header.content_revision);
+
+        /* `vram_module[16]` in the headers is longer than the actual table */
+        let (modules, straps) = match (format_revision, content_revision) {
+            (2, 3) => {
+                let h: atom_vram_info_header_v2_3 = self.read_table_unchecked_size(offset)?;
+                let modules = self.read_vram_modules::<atom_vram_module_v9>(
+                    offset + offset_of!(atom_vram_info_header_v2_3, vram_module),
+                    h.vram_module_num,
+                );
+
+                (modules, self.read_umc_init_reg_block_straps(offset, h.mem_clk_patch_tbloffset))
+            },
+            (2, 4) => {
+                let h: atom_vram_info_header_v2_4 = self.read_table_unchecked_size(offset)?;
+                let modules = self.read_vram_modules::<atom_vram_module_v10>(
+                    offset + offset_of!(atom_vram_info_header_v2_4, vram_module),
+                    h.vram_module_num,
+                );
+
+                (modules, self.read_umc_init_reg_block_straps(offset, h.mem_clk_patch_tbloffset))
+            },
+            (2, 5) => {
+                let h: atom_vram_info_header_v2_5 = self.read_table_unchecked_size(offset)?;
+                let modules = self.read_vram_modules::<atom_vram_module_v11>(
+                    offset + offset_of!(atom_vram_info_header_v2_5, vram_module),
+                    h.vram_module_num,
+                );
+
+                (modules, self.read_gddr6_ac_timing_straps(offset, h.gddr6_ac_timing_offset))
+            },
+            (2, 6) => {
+                let h: atom_vram_info_header_v2_6 = self.read_table_unchecked_size(offset)?;
+                let modules = self.read_vram_modules::<atom_vram_module_v9>(
+                    offset + offset_of!(atom_vram_info_header_v2_6, vram_module),
+                    h.vram_module_num,
+                );
+
+                (modules, self.read_umc_init_reg_block_straps(offset, h.mem_clk_patch_tbloffset))
+            },
+            (3, 0) => {
+                let h: atom_vram_info_header_v3_0 = self.read_table_unchecked_size(offset)?;
+                let modules = self.read_vram_modules::<atom_vram_module_v3_0>(
+                    offset + offset_of!(atom_vram_info_header_v3_0, vram_module),
+                    h.vram_module_num,
+                ).into_iter().map(|mut m| {
+                    /* memory type and channel config are shared by all modules in v3.0 */
+                    m.memory_type = VbiosVramType::from(h.memory_type);
+                    m.channel_num = h.channel_num;
+                    m.channel_width = h.channel_width;
+                    m.channel_enable = Some(h.channel_enable);
+
+                    m
+                }).collect();
+
+                (modules, Vec::new())
+            },
+            _ => return None,
+        };
+
+        Some(VbiosVramInfo { format_revision, content_revision, modules, straps })
+    }
+
+    fn read_vram_modules<M: AtomVramModule>(&self, offset: usize, num: u8) -> Vec<VbiosVramModule> {
+        let mut modules = Vec::with_capacity(num as usize);
+        let mut offset = offset;
+
+        for _ in 0..num {
+            let Some(m) = self.read_struct::<M>(offset) else { break };
+            let size = m.module_size();
+
+            modules.push(m.to_vram_module());
+
+            if size == 0 { break }
+
+            offset += size;
+        }
+
+        modules
+    }
+
+    /// `atom_umc_init_reg_block`, the offset is relative to the start of `vram_info`
+    fn read_umc_init_reg_block_straps(&self, vram_info_offset: usize, offset: u16) -> Vec<VbiosVramStrap> {
+        if offset == 0 { return Vec::new() }
+
+        let offset = vram_info_offset + offset as usize;
+        let Some(reg_num) = self.read_struct::<u16>(offset) else { return Vec::new() };
+        let reg_num = reg_num as usize;
+        /* umc_reg_num, reserved, umc_reg_list[umc_reg_num] */
+        let list_offset = offset + 4 + reg_num * size_of::<u32>();
+        /* block_id, u32umc_reg_data[umc_reg_num] */
+        let data_block_size = (reg_num + 1) * size_of::<u32>();
+
+        self.read_strap_list(list_offset, data_block_size)
+    }
+
+    /// `atom_gddr6_ac_timing_v2_5`, the offset is relative to the start of `vram_info`
+    fn read_gddr6_ac_timing_straps(&self, vram_info_offset: usize, offset: u16) -> Vec<VbiosVramStrap> {
+        if offset == 0 { return Vec::new() }
+
+        self.read_strap_list(
+            vram_info_offset + offset as usize,
+            size_of::<atom_gddr6_ac_timing_v2_5>(),
+        )
+    }
+
+    /// The list is terminated by zero
+    fn read_strap_list(&self, offset: usize, entry_size: usize) -> Vec<VbiosVramStrap> {
+        let mut straps = Vec::new();
+        let mut offset = offset;
+
+        while let Some(id) = self.read_struct::<u32>(offset) {
+            if id == 0 { break }
+
+            straps.push(VbiosVramStrap::from_umc_id_access(id));
+            offset += entry_size;
+        }
+
+        straps
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VbiosVramInfo {
+    pub format_revision: u8,
+    pub content_revision: u8,
+    pub modules: Vec<VbiosVramModule>,
+    /// Memory clock straps from `mem_clk_patch` (v2.3, v2.4, v2.6) or `gddr6_ac_timing` (v2.5)
+    pub straps: Vec<VbiosVramStrap>,
+}
+
+impl VbiosVramInfo {
+    /// Straps that apply to the memory module with `ext_memory_id`
+    pub fn straps_for_module(&self, ext_memory_id: u8) -> Vec<VbiosVramStrap> {
+        self.straps.iter().filter(|s| s.mem_blk_id == ext_memory_id).copied().collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VbiosVramModule {
+    /// Memory module ID, selected by the memory strap of the board
+    pub ext_memory_id: u8,
+    pub vendor: VramVendor,
+    /// `[7:4]` of `vender_rev_id`
+    pub vendor_revision: u8,
+    pub memory_type: VbiosVramType,
+    pub density: u8,
+    pub channel_num: u8,
+    /// log2 of the channel width in bits
+    pub channel_width: u8,
+    pub channel_enable: Option<u32>,
+    /// MiB, not available in v3.0
+    pub memory_size: Option<u32>,
+    /// 10 kHz, not available in v2.5 and v3.0
+    pub max_mem_clk: Option<u32>,
+    pub part_number: String,
+}
+
+impl VbiosVramModule {
+    pub fn bus_width(&self) -> u32 {
+        let width = 1u32.checked_shl(self.channel_width as u32).unwrap_or(0);
+
+        self.channel_num as u32 * width
+    }
+}
+
+/// `atom_umc_reg_setting_id_config`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VbiosVramStrap {
+    /// `ext_memory_id` of the memory module
+    pub mem_blk_id: u8,
+    /// Upper limit of the memory clock for this strap, 10 kHz
+    pub mem_clk_range: u32,
+}
+
+impl VbiosVramStrap {
+    pub fn from_umc_id_access(id: u32) -> Self {
+        Self {
+            mem_blk_id: (id >> 24) as u8,
+            mem_clk_range: id & 0xFF_FFFF,
+        }
+    }
+
+    pub fn mem_clk_range_mhz(&self) -> u32 {
+        self.mem_clk_range / 100
+    }
+}
+
+/// `enum atom_dgpu_vram_type`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VbiosVramType {
+    GDDR5,
+    HBM2,
+    HBM2E,
+    GDDR6,
+    HBM3,
+    Unknown(u8),
+}
+
+impl From<u8> for VbiosVramType {
+    fn from(value: u8) -> Self {
+        match value as u32 {
+            atom_dgpu_vram_type_ATOM_DGPU_VRAM_TYPE_GDDR5 => Self::GDDR5,
+            atom_dgpu_vram_type_ATOM_DGPU_VRAM_TYPE_HBM2 => Self::HBM2,
+            atom_dgpu_vram_type_ATOM_DGPU_VRAM_TYPE_HBM2E => Self::HBM2E,
+            atom_dgpu_vram_type_ATOM_DGPU_VRAM_TYPE_GDDR6 => Self::GDDR6,
+            atom_dgpu_vram_type_ATOM_DGPU_VRAM_TYPE_HBM3 => Self::HBM3,
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+impl VbiosVramType {
+    /* ref: convert_atom_mem_type_to_vram_type */
+    pub fn to_vram_type(&self) -> VRAM_TYPE {
+        match self {
+            Self::GDDR5 => VRAM_TYPE::GDDR5,
+            Self::HBM2 |
+            Self::HBM2E |
+            Self::HBM3 => VRAM_TYPE::HBM,
+            Self::GDDR6 => VRAM_TYPE::GDDR6,
+            Self::Unknown(_) => VRAM_TYPE::UNKNOWN,
+        }
+    }
+}
+
+impl fmt::Display for VbiosVramType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Memory vendor code, `[3:0]` of `vender_rev_id`
+/// ref: drivers/gpu/drm/amd/include/atombios.h
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VramVendor {
+    Samsung,
+    /// Infineon/Qimonda
+    Infineon,
+    Elpida,
+    Etron,
+    Nanya,
+    /// SK hynix
+    Hynix,
+    Mosel,
+    Winbond,
+    ESMT,
+    Micron,
+    Unknown(u8),
+}
+
+impl From<u8> for VramVendor {
+    fn from(value: u8) -> Self {
+        match value & 0xF {
+            0x1 => Self::Samsung,
+            0x2 => Self::Infineon,
+            0x3 => Self::Elpida,
+            0x4 => Self::Etron,
+            0x5 => Self::Nanya,
+            0x6 => Self::Hynix,
+            0x7 => Self::Mosel,
+            0x8 => Self::Winbond,
+            0x9 => Self::ESMT,
+            0xF => Self::Micron,
+            v => Self::Unknown(v),
+        }
+    }
+}
+
+impl fmt::Display for VramVendor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+fn part_number(s: &[c_char]) -> String {
+    let bytes: Vec<u8> = s.iter().map(|c| *c as u8).take_while(|c| *c != 0).collect();
+
+    String::from_utf8_lossy(&bytes).trim().to_string()
+}
+
+trait AtomVramModule {
+    fn module_size(&self) -> usize;
+    fn to_vram_module(&self) -> VbiosVramModule;
+}
+
+macro_rules! impl_atom_vram_module_v9_v11 {
+    ($module: ty $(, $max_mem_clk: ident)?) => {
+        impl AtomVramModule for $module {
+            fn module_size(&self) -> usize {
+                self.vram_module_size as usize
+            }
+
+            fn to_vram_module(&self) -> VbiosVramModule {
+                let max_mem_clk: Option<u32> = None $(.or(Some(self.$max_mem_clk)))?;
+
+                VbiosVramModule {
+                    ext_memory_id: self.ext_memory_id,
+                    vendor: VramVendor::from(self.vender_rev_id),
+                    vendor_revision: self.vender_rev_id >> 4,
+                    memory_type: VbiosVramType::from(self.memory_type),
+                    density: self.density,
+                    channel_num: self.channel_num,
+                    channel_width: self.channel_width,
+                    channel_enable: Some(self.channel_enable),
+                    memory_size: Some(self.memory_size),
+                    max_mem_clk,
+                    part_number: part_number(&{ self.dram_pnstring }),
+                }
+            }
+        }
+    };
+}
+
+impl_atom_vram_module_v9_v11!(atom_vram_module_v9, max_mem_clk);
+impl_atom_vram_module_v9_v11!(atom_vram_module_v10, max_mem_clk);
+impl_atom_vram_module_v9_v11!(atom_vram_module_v11);
+
+impl AtomVramModule for atom_vram_module_v3_0 {
+    fn module_size(&self) -> usize {
+        size_of::<Self>()
+    }
+
+    fn to_vram_module(&self) -> VbiosVramModule {
+        VbiosVramModule {
+            ext_memory_id: self.ext_memory_id,
+            vendor: VramVendor::from(self.dram_vendor_id),
+            vendor_revision: self.dram_vendor_id >> 4,
+            memory_type: VbiosVramType::Unknown(0),
+            density: self.density,
+            channel_num: 0,
+            channel_width: 0,
+            channel_enable: None,
+            memory_size: None,
+            max_mem_clk: None,
+            part_number: part_number(&{ self.dram_pnstring }),
+        }
+    }
+}
+
+#[test]
+fn test_vram_info_v2_4() {
+    use crate::bindings::atom_common_table_header;
+
+    const VRAM_INFO_OFFSET: usize = 0x10;
+    let module_offset = VRAM_INFO_OFFSET + offset_of!(atom_vram_info_header_v2_4, vram_module);
+    let module_size = size_of::<atom_vram_module_v10>();
+    let mem_clk_patch_offset = module_offset + module_size - VRAM_INFO_OFFSET;
+
+    let mut bin = vec![0u8; module_offset + module_size];
+    let header = atom_common_table_header {
+        structuresize: (module_offset + module_size - VRAM_INFO_OFFSET) as u16,
+        format_revision: 2,
+        content_revision: 4,
+    };
+
+    bin[VRAM_INFO_OFFSET..VRAM_INFO_OFFSET+2].copy_from_slice(&{ header.structuresize }.to_le_bytes());
+    bin[VRAM_INFO_OFFSET+2] = header.format_revision;
+    bin[VRAM_INFO_OFFSET+3] = header.content_revision;
+    bin[VRAM_INFO_OFFSET + offset_of!(atom_vram_info_header_v2_4, mem_clk_patch_tbloffset)..][..2]
+        .copy_from_slice(&(mem_clk_patch_offset as u16).to_le_bytes());
+    bin[VRAM_INFO_OFFSET + offset_of!(atom_vram_info_header_v2_4, vram_module_num)] = 1;
+
+    let m = module_offset;
+    bin[m..m+4].copy_from_slice(&8192u32.to_le_bytes());
+    bin[m+8..m+12].copy_from_slice(&100_000u32.to_le_bytes());
+    bin[m+offset_of!(atom_vram_module_v10, vram_module_size)..][..2]
+        .copy_from_slice(&(module_size as u16).to_le_bytes());
+    bin[m+offset_of!(atom_vram_module_v10, memory_type)] = 0x70;
+    bin[m+offset_of!(atom_vram_module_v10, channel_num)] = 16;
+    bin[m+offset_of!(atom_vram_module_v10, channel_width)] = 4;
+    bin[m+offset_of!(atom_vram_module_v10, vender_rev_id)] = 0x16;
+    bin[m+offset_of!(atom_vram_module_v10, dram_pnstring)..][..5].copy_from_slice(b"H56C8");
+
+    /* atom_umc_init_reg_block: 1 register, 2 straps */
+    bin.extend_from_slice(&[1, 0, 0, 0]);
+    bin.extend_from_slice(&0x1234u32.to_le_bytes());
+    bin.extend_from_slice(&(100_000u32).to_le_bytes());
+    bin.extend_from_slice(&0u32.to_le_bytes());
+    bin.extend_from_slice(&((1 << 24) | 200_000u32).to_le_bytes());
+    bin.extend_from_slice(&0u32.to_le_bytes());
+    bin.extend_from_slice(&0u32.to_le_bytes());
+
+    let mut data_table: atom_master_data_table_v2_1 = unsafe { core::mem::zeroed() };
+    data_table.listOfdatatables.vram_info = VRAM_INFO_OFFSET as u16;
+
+    let vram_info = VbiosParser::new(bin).get_vram_info(&data_table).unwrap();
+    let module = &vram_info.modules[0];
+
+    assert_eq!(vram_info.modules.len(), 1);
+    assert_eq!(module.vendor, VramVendor::Hynix);
+    assert_eq!(module.vendor_revision, 1);
+    assert_eq!(module.memory_type, VbiosVramType::GDDR6);
+    assert_eq!(module.memory_size, Some(8192));
+    assert_eq!(module.bus_width(), 256);
+    assert_eq!(module.part_number, "H56C8");
+    assert_eq!(
+        vram_info.straps,
+        [
+            VbiosVramStrap { mem_blk_id: 0, mem_clk_range: 100_000 },
+            VbiosVramStrap { mem_blk_id: 1, mem_clk_range: 200_000 },
+        ],
+    );
+    assert_eq!(vram_info.straps_for_module(1)[0].mem_clk_range_mhz(), 2000);
+}
@@ -0,0 +1,452 @@
+/* a union of the bitfields and `susAccess`, use `usFirmwareCapability` as `USHORT` */
+pub type ATOM_FIRMWARE_CAPABILITY_ACCESS = USHORT;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct __BindgenBitfieldUnit<Storage> {
+    storage: Storage,
+}
+impl<Storage> __BindgenBitfieldUnit<Storage> {
+    #[inline]
+    pub const fn new(storage: Storage) -> Self {
+        Self { storage }
+    }
+}
+impl<Storage> __BindgenBitfieldUnit<Storage>
+where
+    Storage: AsRef<[u8]> + AsMut<[u8]>,
+{
+    #[inline]
+    fn extract_bit(byte: u8, index: usize) -> bool {
+        let bit_index = if cfg!(target_endian = "big") {
+            7 - (index % 8)
+        } else {
+            index % 8
+        };
+        let mask = 1 << bit_index;
+        byte & mask == mask
+    }
+    #[inline]
+    pub fn get_bit(&self, index: usize) -> bool {
+        debug_assert!(index / 8 < self.storage.as_ref().len());
+        let byte_index = index / 8;
+        let byte = self.storage.as_ref()[byte_index];
+        Self::extract_bit(byte, index)
+    }
+    #[inline]
+    pub unsafe fn raw_get_bit(this: *const Self, index: usize) -> bool {
+        debug_assert!(index / 8 < core::mem::size_of::<Storage>());
+        let byte_index = index / 8;
+        let byte = unsafe {
+            *(core::ptr::addr_of!((*this).storage) as *const u8).offset(byte_index as isize)
+        };
+        Self::extract_bit(byte, index)
+    }
+    #[inline]
+    fn change_bit(byte: u8, index: usize, val: bool) -> u8 {
+        let bit_index = if cfg!(target_endian = "big") {
+            7 - (index % 8)
+        } else {
+            index % 8
+        };
+        let mask = 1 << bit_index;
+        if val { byte | mask } else { byte & !mask }
+    }
+    #[inline]
+    pub fn set_bit(&mut self, index: usize, val: bool) {
+        debug_assert!(index / 8 < self.storage.as_ref().len());
+        let byte_index = index / 8;
+        let byte = &mut self.storage.as_mut()[byte_index];
+        *byte = Self::change_bit(*byte, index, val);
+    }
+    #[inline]
+    pub unsafe fn raw_set_bit(this: *mut Self, index: usize, val: bool) {
+        debug_assert!(index / 8 < core::mem::size_of::<Storage>());
+        let byte_index = index / 8;
+        let byte = unsafe {
+            (core::ptr::addr_of_mut!((*this).storage) as *mut u8).offset(byte_index as isize)
+        };
+        unsafe { *byte = Self::change_bit(*byte, index, val) };
+    }
+    #[inline]
+    pub fn get(&self, bit_offset: usize, bit_width: u8) -> u64 {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self.storage.as_ref().len());
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= self.storage.as_ref().len());
+        let mut val = 0;
+        for i in 0..(bit_width as usize) {
+            if self.get_bit(i + bit_offset) {
+                let index = if cfg!(target_endian = "big") {
+                    bit_width as usize - 1 - i
+                } else {
+                    i
+                };
+                val |= 1 << index;
+            }
+        }
+        val
+    }
+    #[inline]
+    pub unsafe fn raw_get(this: *const Self, bit_offset: usize, bit_width: u8) -> u64 {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < core::mem::size_of::<Storage>());
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= core::mem::size_of::<Storage>());
+        let mut val = 0;
+        for i in 0..(bit_width as usize) {
+            if unsafe { Self::raw_get_bit(this, i + bit_offset) } {
+                let index = if cfg!(target_endian = "big") {
+                    bit_width as usize - 1 - i
+                } else {
+                    i
+                };
+                val |= 1 << index;
+            }
+        }
+        val
+    }
+    #[inline]
+    pub fn set(&mut self, bit_offset: usize, bit_width: u8, val: u64) {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self.storage.as_ref().len());
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= self.storage.as_ref().len());
+        for i in 0..(bit_width as usize) {
+            let mask = 1 << i;
+            let val_bit_is_set = val & mask == mask;
+            let index = if cfg!(target_endian = "big") {
+                bit_width as usize - 1 - i
+            } else {
+                i
+            };
+            self.set_bit(index + bit_offset, val_bit_is_set);
+        }
+    }
+    #[inline]
+    pub unsafe fn raw_set(this: *mut Self, bit_offset: usize, bit_width: u8, val: u64) {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < core::mem::size_of::<Storage>());
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= core::mem::size_of::<Storage>());
+        for i in 0..(bit_width as usize) {
+            let mask = 1 << i;
+            let val_bit_is_set = val & mask == mask;
+            let index = if cfg!(target_endian = "big") {
+                bit_width as usize - 1 - i
+            } else {
+                i
+            };
+            unsafe { Self::raw_set_bit(this, index + bit_offset, val_bit_is_set) };
+        }
+    }
+}
+pub type USHORT = u16;
+pub type ULONG = u32;
+pub type UCHAR = u8;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ATOM_COMMON_TABLE_HEADER {
+    pub usStructureSize: USHORT,
+    pub ucTableFormatRevision: UCHAR,
+    pub ucTableContentRevision: UCHAR,
+}
+pub type ATOM_COMMON_TABLE_HEADER = _ATOM_COMMON_TABLE_HEADER;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ATOM_ROM_HEADER {
+    pub sHeader: ATOM_COMMON_TABLE_HEADER,
+    pub uaFirmWareSignature: [UCHAR; 4usize],
+    pub usBiosRuntimeSegmentAddress: USHORT,
+    pub usProtectedModeInfoOffset: USHORT,
+    pub usConfigFilenameOffset: USHORT,
+    pub usCRC_BlockOffset: USHORT,
+    pub usBIOS_BootupMessageOffset: USHORT,
+    pub usInt10Offset: USHORT,
+    pub usPciBusDevInitCode: USHORT,
+    pub usIoBaseAddress: USHORT,
+    pub usSubsystemVendorID: USHORT,
+    pub usSubsystemID: USHORT,
+    pub usPCI_InfoOffset: USHORT,
+    pub usMasterCommandTableOffset: USHORT,
+    pub usMasterDataTableOffset: USHORT,
+    pub ucExtendedFunctionCode: UCHAR,
+    pub ucReserved: UCHAR,
+}
+pub type ATOM_ROM_HEADER = _ATOM_ROM_HEADER;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ATOM_ROM_HEADER_V2_1 {
+    pub sHeader: ATOM_COMMON_TABLE_HEADER,
+    pub uaFirmWareSignature: [UCHAR; 4usize],
+    pub usBiosRuntimeSegmentAddress: USHORT,
+    pub usProtectedModeInfoOffset: USHORT,
+    pub usConfigFilenameOffset: USHORT,
+    pub usCRC_BlockOffset: USHORT,
+    pub usBIOS_BootupMessageOffset: USHORT,
+    pub usInt10Offset: USHORT,
+    pub usPciBusDevInitCode: USHORT,
+    pub usIoBaseAddress: USHORT,
+    pub usSubsystemVendorID: USHORT,
+    pub usSubsystemID: USHORT,
+    pub usPCI_InfoOffset: USHORT,
+    pub usMasterCommandTableOffset: USHORT,
+    pub usMasterDataTableOffset: USHORT,
+    pub ucExtendedFunctionCode: UCHAR,
+    pub ucReserved: UCHAR,
+    pub ulPSPDirTableOffset: ULONG,
+}
+pub type ATOM_ROM_HEADER_V2_1 = _ATOM_ROM_HEADER_V2_1;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ATOM_MASTER_LIST_OF_DATA_TABLES {
+    pub UtilityPipeLine: USHORT,
+    pub MultimediaCapabilityInfo: USHORT,
+    pub MultimediaConfigInfo: USHORT,
+    pub StandardVESA_Timing: USHORT,
+    pub FirmwareInfo: USHORT,
+    pub PaletteData: USHORT,
+    pub LCD_Info: USHORT,
+    pub DIGTransmitterInfo: USHORT,
+    pub SMU_Info: USHORT,
+    pub SupportedDevicesInfo: USHORT,
+    pub GPIO_I2C_Info: USHORT,
+    pub VRAM_UsageByFirmware: USHORT,
+    pub GPIO_Pin_LUT: USHORT,
+    pub VESA_ToInternalModeLUT: USHORT,
+    pub GFX_Info: USHORT,
+    pub PowerPlayInfo: USHORT,
+    pub GPUVirtualizationInfo: USHORT,
+    pub SaveRestoreInfo: USHORT,
+    pub PPLL_SS_Info: USHORT,
+    pub OemInfo: USHORT,
+    pub XTMDS_Info: USHORT,
+    pub MclkSS_Info: USHORT,
+    pub Object_Header: USHORT,
+    pub IndirectIOAccess: USHORT,
+    pub MC_InitParameter: USHORT,
+    pub ASIC_VDDC_Info: USHORT,
+    pub ASIC_InternalSS_Info: USHORT,
+    pub TV_VideoMode: USHORT,
+    pub VRAM_Info: USHORT,
+    pub MemoryTrainingInfo: USHORT,
+    pub IntegratedSystemInfo: USHORT,
+    pub ASIC_ProfilingInfo: USHORT,
+    pub VoltageObjectInfo: USHORT,
+    pub PowerSourceInfo: USHORT,
+    pub ServiceInfo: USHORT,
+}
+pub type ATOM_MASTER_LIST_OF_DATA_TABLES = _ATOM_MASTER_LIST_OF_DATA_TABLES;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ATOM_MASTER_DATA_TABLE {
+    pub sHeader: ATOM_COMMON_TABLE_HEADER,
+    pub ListOfDataTables: ATOM_MASTER_LIST_OF_DATA_TABLES,
+}
+pub type ATOM_MASTER_DATA_TABLE = _ATOM_MASTER_DATA_TABLE;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ATOM_FIRMWARE_INFO_V2_1 {
+    pub sHeader: ATOM_COMMON_TABLE_HEADER,
+    pub ulFirmwareRevision: ULONG,
+    pub ulDefaultEngineClock: ULONG,
+    pub ulDefaultMemoryClock: ULONG,
+    pub ulReserved1: ULONG,
+    pub ulReserved2: ULONG,
+    pub ulMaxEngineClockPLL_Output: ULONG,
+    pub ulMaxMemoryClockPLL_Output: ULONG,
+    pub ulMaxPixelClockPLL_Output: ULONG,
+    pub ulBinaryAlteredInfo: ULONG,
+    pub ulDefaultDispEngineClkFreq: ULONG,
+    pub ucReserved1: UCHAR,
+    pub ucMinAllowedBL_Level: UCHAR,
+    pub usBootUpVDDCVoltage: USHORT,
+    pub usLcdMinPixelClockPLL_Output: USHORT,
+    pub usLcdMaxPixelClockPLL_Output: USHORT,
+    pub ulReserved4: ULONG,
+    pub ulMinPixelClockPLL_Output: ULONG,
+    pub usMinEngineClockPLL_Input: USHORT,
+    pub usMaxEngineClockPLL_Input: USHORT,
+    pub usMinEngineClockPLL_Output: USHORT,
+    pub usMinMemoryClockPLL_Input: USHORT,
+    pub usMaxMemoryClockPLL_Input: USHORT,
+    pub usMinMemoryClockPLL_Output: USHORT,
+    pub usMaxPixelClock: USHORT,
+    pub usMinPixelClockPLL_Input: USHORT,
+    pub usMaxPixelClockPLL_Input: USHORT,
+    pub usMinPixelClockPLL_Output: USHORT,
+    pub usFirmwareCapability: ATOM_FIRMWARE_CAPABILITY_ACCESS,
+    pub usCoreReferenceClock: USHORT,
+    pub usMemoryReferenceClock: USHORT,
+    pub usUniphyDPModeExtClkFreq: USHORT,
+    pub ucMemoryModule_ID: UCHAR,
+    pub ucReserved4: [UCHAR; 3usize],
+}
+pub type ATOM_FIRMWARE_INFO_V2_1 = _ATOM_FIRMWARE_INFO_V2_1;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _PRODUCT_BRANDING {
+    pub _bitfield_align_1: [u8; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 1usize]>,
+}
+impl _PRODUCT_BRANDING {
+    #[inline]
+    pub fn ucEMBEDDED_CAP(&self) -> UCHAR {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 2u8) as u8) }
+    }
+    #[inline]
+    pub fn set_ucEMBEDDED_CAP(&mut self, val: UCHAR) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            self._bitfield_1.set(0usize, 2u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn ucEMBEDDED_CAP_raw(this: *const Self) -> UCHAR {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 1usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                0usize,
+                2u8,
+            ) as u8)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_ucEMBEDDED_CAP_raw(this: *mut Self, val: UCHAR) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 1usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                0usize,
+                2u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn ucReserved(&self) -> UCHAR {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(2usize, 2u8) as u8) }
+    }
+    #[inline]
+    pub fn set_ucReserved(&mut self, val: UCHAR) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            self._bitfield_1.set(2usize, 2u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn ucReserved_raw(this: *const Self) -> UCHAR {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 1usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                2usize,
+                2u8,
+            ) as u8)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_ucReserved_raw(this: *mut Self, val: UCHAR) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 1usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                2usize,
+                2u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn ucBRANDING_ID(&self) -> UCHAR {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(4usize, 4u8) as u8) }
+    }
+    #[inline]
+    pub fn set_ucBRANDING_ID(&mut self, val: UCHAR) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            self._bitfield_1.set(4usize, 4u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn ucBRANDING_ID_raw(this: *const Self) -> UCHAR {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 1usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                4usize,
+                4u8,
+            ) as u8)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_ucBRANDING_ID_raw(this: *mut Self, val: UCHAR) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 1usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                4usize,
+                4u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn new_bitfield_1(
+        ucEMBEDDED_CAP: UCHAR,
+        ucReserved: UCHAR,
+        ucBRANDING_ID: UCHAR,
+    ) -> __BindgenBitfieldUnit<[u8; 1usize]> {
+        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 1usize]> = Default::default();
+        __bindgen_bitfield_unit.set(0usize, 2u8, {
+            let ucEMBEDDED_CAP: u8 = unsafe { ::core::mem::transmute(ucEMBEDDED_CAP) };
+            ucEMBEDDED_CAP as u64
+        });
+        __bindgen_bitfield_unit.set(2usize, 2u8, {
+            let ucReserved: u8 = unsafe { ::core::mem::transmute(ucReserved) };
+            ucReserved as u64
+        });
+        __bindgen_bitfield_unit.set(4usize, 4u8, {
+            let ucBRANDING_ID: u8 = unsafe { ::core::mem::transmute(ucBRANDING_ID) };
+            ucBRANDING_ID as u64
+        });
+        __bindgen_bitfield_unit
+    }
+}
+pub type PRODUCT_BRANDING = _PRODUCT_BRANDING;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ATOM_FIRMWARE_INFO_V2_2 {
+    pub sHeader: ATOM_COMMON_TABLE_HEADER,
+    pub ulFirmwareRevision: ULONG,
+    pub ulDefaultEngineClock: ULONG,
+    pub ulDefaultMemoryClock: ULONG,
+    pub ulSPLL_OutputFreq: ULONG,
+    pub ulGPUPLL_OutputFreq: ULONG,
+    pub ulReserved1: ULONG,
+    pub ulReserved2: ULONG,
+    pub ulMaxPixelClockPLL_Output: ULONG,
+    pub ulBinaryAlteredInfo: ULONG,
+    pub ulDefaultDispEngineClkFreq: ULONG,
+    pub ucReserved3: UCHAR,
+    pub ucMinAllowedBL_Level: UCHAR,
+    pub usBootUpVDDCVoltage: USHORT,
+    pub usLcdMinPixelClockPLL_Output: USHORT,
+    pub usLcdMaxPixelClockPLL_Output: USHORT,
+    pub ulReserved4: ULONG,
+    pub ulMinPixelClockPLL_Output: ULONG,
+    pub ucRemoteDisplayConfig: UCHAR,
+    pub ucReserved5: [UCHAR; 3usize],
+    pub ulReserved6: ULONG,
+    pub ulReserved7: ULONG,
+    pub usReserved11: USHORT,
+    pub usMinPixelClockPLL_Input: USHORT,
+    pub usMaxPixelClockPLL_Input: USHORT,
+    pub usBootUpVDDCIVoltage: USHORT,
+    pub usFirmwareCapability: ATOM_FIRMWARE_CAPABILITY_ACCESS,
+    pub usCoreReferenceClock: USHORT,
+    pub usMemoryReferenceClock: USHORT,
+    pub usUniphyDPModeExtClkFreq: USHORT,
+    pub ucMemoryModule_ID: UCHAR,
+    pub ucCoolingSolution_ID: UCHAR,
+    pub ucProductBranding: PRODUCT_BRANDING,
+    pub ucReserved9: UCHAR,
+    pub usBootUpMVDDCVoltage: USHORT,
+    pub usBootUpVDDGFXVoltage: USHORT,
+    pub ulReserved10: [ULONG; 3usize],
+}
+pub type ATOM_FIRMWARE_INFO_V2_2 = _ATOM_FIRMWARE_INFO_V2_2;
@@ -0,0 +1,1035 @@
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct __BindgenBitfieldUnit<Storage> {
+    storage: Storage,
+}
+impl<Storage> __BindgenBitfieldUnit<Storage> {
+    #[inline]
+    pub const fn new(storage: Storage) -> Self {
+        Self { storage }
+    }
+}
+impl<Storage> __BindgenBitfieldUnit<Storage>
+where
+    Storage: AsRef<[u8]> + AsMut<[u8]>,
+{
+    #[inline]
+    fn extract_bit(byte: u8, index: usize) -> bool {
+        let bit_index = if cfg!(target_endian = "big") {
+            7 - (index % 8)
+        } else {
+            index % 8
+        };
+        let mask = 1 << bit_index;
+        byte & mask == mask
+    }
+    #[inline]
+    pub fn get_bit(&self, index: usize) -> bool {
+        debug_assert!(index / 8 < self.storage.as_ref().len());
+        let byte_index = index / 8;
+        let byte = self.storage.as_ref()[byte_index];
+        Self::extract_bit(byte, index)
+    }
+    #[inline]
+    pub unsafe fn raw_get_bit(this: *const Self, index: usize) -> bool {
+        debug_assert!(index / 8 < core::mem::size_of::<Storage>());
+        let byte_index = index / 8;
+        let byte = unsafe {
+            *(core::ptr::addr_of!((*this).storage) as *const u8).offset(byte_index as isize)
+        };
+        Self::extract_bit(byte, index)
+    }
+    #[inline]
+    fn change_bit(byte: u8, index: usize, val: bool) -> u8 {
+        let bit_index = if cfg!(target_endian = "big") {
+            7 - (index % 8)
+        } else {
+            index % 8
+        };
+        let mask = 1 << bit_index;
+        if val { byte | mask } else { byte & !mask }
+    }
+    #[inline]
+    pub fn set_bit(&mut self, index: usize, val: bool) {
+        debug_assert!(index / 8 < self.storage.as_ref().len());
+        let byte_index = index / 8;
+        let byte = &mut self.storage.as_mut()[byte_index];
+        *byte = Self::change_bit(*byte, index, val);
+    }
+    #[inline]
+    pub unsafe fn raw_set_bit(this: *mut Self, index: usize, val: bool) {
+        debug_assert!(index / 8 < core::mem::size_of::<Storage>());
+        let byte_index = index / 8;
+        let byte = unsafe {
+            (core::ptr::addr_of_mut!((*this).storage) as *mut u8).offset(byte_index as isize)
+        };
+        unsafe { *byte = Self::change_bit(*byte, index, val) };
+    }
+    #[inline]
+    pub fn get(&self, bit_offset: usize, bit_width: u8) -> u64 {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self.storage.as_ref().len());
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= self.storage.as_ref().len());
+        let mut val = 0;
+        for i in 0..(bit_width as usize) {
+            if self.get_bit(i + bit_offset) {
+                let index = if cfg!(target_endian = "big") {
+                    bit_width as usize - 1 - i
+                } else {
+                    i
+                };
+                val |= 1 << index;
+            }
+        }
+        val
+    }
+    #[inline]
+    pub unsafe fn raw_get(this: *const Self, bit_offset: usize, bit_width: u8) -> u64 {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < core::mem::size_of::<Storage>());
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= core::mem::size_of::<Storage>());
+        let mut val = 0;
+        for i in 0..(bit_width as usize) {
+            if unsafe { Self::raw_get_bit(this, i + bit_offset) } {
+                let index = if cfg!(target_endian = "big") {
+                    bit_width as usize - 1 - i
+                } else {
+                    i
+                };
+                val |= 1 << index;
+            }
+        }
+        val
+    }
+    #[inline]
+    pub fn set(&mut self, bit_offset: usize, bit_width: u8, val: u64) {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < self.storage.as_ref().len());
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= self.storage.as_ref().len());
+        for i in 0..(bit_width as usize) {
+            let mask = 1 << i;
+            let val_bit_is_set = val & mask == mask;
+            let index = if cfg!(target_endian = "big") {
+                bit_width as usize - 1 - i
+            } else {
+                i
+            };
+            self.set_bit(index + bit_offset, val_bit_is_set);
+        }
+    }
+    #[inline]
+    pub unsafe fn raw_set(this: *mut Self, bit_offset: usize, bit_width: u8, val: u64) {
+        debug_assert!(bit_width <= 64);
+        debug_assert!(bit_offset / 8 < core::mem::size_of::<Storage>());
+        debug_assert!((bit_offset + (bit_width as usize)) / 8 <= core::mem::size_of::<Storage>());
+        for i in 0..(bit_width as usize) {
+            let mask = 1 << i;
+            let val_bit_is_set = val & mask == mask;
+            let index = if cfg!(target_endian = "big") {
+                bit_width as usize - 1 - i
+            } else {
+                i
+            };
+            unsafe { Self::raw_set_bit(this, index + bit_offset, val_bit_is_set) };
+        }
+    }
+}
+pub const PSP_HEADER_SIZE: u32 = 256;
+pub const BINARY_SIGNATURE: u32 = 673256455;
+pub const DISCOVERY_TABLE_SIGNATURE: u32 = 1396985929;
+pub const GC_TABLE_ID: u32 = 17223;
+pub const HARVEST_TABLE_SIGNATURE: u32 = 1448231240;
+pub const VCN_INFO_TABLE_ID: u32 = 5129046;
+pub const MALL_INFO_TABLE_ID: u32 = 1280065869;
+pub const table_IP_DISCOVERY: table = 0;
+pub const table_GC: table = 1;
+pub const table_HARVEST_INFO: table = 2;
+pub const table_VCN_INFO: table = 3;
+pub const table_MALL_INFO: table = 4;
+pub const table_NPS_INFO: table = 5;
+pub const table_TOTAL_TABLES: table = 6;
+pub type table = ::core::ffi::c_uint;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct table_info {
+    pub offset: u16,
+    pub checksum: u16,
+    pub size: u16,
+    pub padding: u16,
+}
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct binary_header {
+    pub binary_signature: u32,
+    pub version_major: u16,
+    pub version_minor: u16,
+    pub binary_checksum: u16,
+    pub binary_size: u16,
+    pub table_list: [table_info; 6usize],
+}
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct die_info {
+    pub die_id: u16,
+    pub die_offset: u16,
+}
+#[repr(C, packed)]
+#[derive(Copy, Clone)]
+pub struct ip_discovery_header {
+    pub signature: u32,
+    pub version: u16,
+    pub size: u16,
+    pub id: u32,
+    pub num_dies: u16,
+    pub die_info: [die_info; 16usize],
+    pub __bindgen_anon_1: ip_discovery_header__bindgen_ty_1,
+}
+#[repr(C, packed)]
+#[derive(Copy, Clone)]
+pub union ip_discovery_header__bindgen_ty_1 {
+    pub padding: [u16; 1usize],
+    pub __bindgen_anon_1: ip_discovery_header__bindgen_ty_1__bindgen_ty_1,
+}
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct ip_discovery_header__bindgen_ty_1__bindgen_ty_1 {
+    pub _bitfield_align_1: [u8; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 1usize]>,
+    pub reserved2: u8,
+}
+impl ip_discovery_header__bindgen_ty_1__bindgen_ty_1 {
+    #[inline]
+    pub fn base_addr_64_bit(&self) -> u8 {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u8) }
+    }
+    #[inline]
+    pub fn set_base_addr_64_bit(&mut self, val: u8) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            self._bitfield_1.set(0usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn base_addr_64_bit_raw(this: *const Self) -> u8 {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 1usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                0usize,
+                1u8,
+            ) as u8)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_base_addr_64_bit_raw(this: *mut Self, val: u8) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 1usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                0usize,
+                1u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn reserved(&self) -> u8 {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(1usize, 7u8) as u8) }
+    }
+    #[inline]
+    pub fn set_reserved(&mut self, val: u8) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            self._bitfield_1.set(1usize, 7u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn reserved_raw(this: *const Self) -> u8 {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 1usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                1usize,
+                7u8,
+            ) as u8)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_reserved_raw(this: *mut Self, val: u8) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 1usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                1usize,
+                7u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn new_bitfield_1(
+        base_addr_64_bit: u8,
+        reserved: u8,
+    ) -> __BindgenBitfieldUnit<[u8; 1usize]> {
+        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 1usize]> = Default::default();
+        __bindgen_bitfield_unit.set(0usize, 1u8, {
+            let base_addr_64_bit: u8 = unsafe { ::core::mem::transmute(base_addr_64_bit) };
+            base_addr_64_bit as u64
+        });
+        __bindgen_bitfield_unit.set(1usize, 7u8, {
+            let reserved: u8 = unsafe { ::core::mem::transmute(reserved) };
+            reserved as u64
+        });
+        __bindgen_bitfield_unit
+    }
+}
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct ip {
+    pub hw_id: u16,
+    pub number_instance: u8,
+    pub num_base_address: u8,
+    pub major: u8,
+    pub minor: u8,
+    pub revision: u8,
+    pub _bitfield_align_1: [u8; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 1usize]>,
+}
+impl ip {
+    #[inline]
+    pub fn harvest(&self) -> u8 {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 4u8) as u8) }
+    }
+    #[inline]
+    pub fn set_harvest(&mut self, val: u8) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            self._bitfield_1.set(0usize, 4u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn harvest_raw(this: *const Self) -> u8 {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 1usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                0usize,
+                4u8,
+            ) as u8)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_harvest_raw(this: *mut Self, val: u8) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 1usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                0usize,
+                4u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn reserved(&self) -> u8 {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(4usize, 4u8) as u8) }
+    }
+    #[inline]
+    pub fn set_reserved(&mut self, val: u8) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            self._bitfield_1.set(4usize, 4u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn reserved_raw(this: *const Self) -> u8 {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 1usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                4usize,
+                4u8,
+            ) as u8)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_reserved_raw(this: *mut Self, val: u8) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 1usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                4usize,
+                4u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn new_bitfield_1(
+        harvest: u8,
+        reserved: u8,
+    ) -> __BindgenBitfieldUnit<[u8; 1usize]> {
+        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 1usize]> = Default::default();
+        __bindgen_bitfield_unit.set(0usize, 4u8, {
+            let harvest: u8 = unsafe { ::core::mem::transmute(harvest) };
+            harvest as u64
+        });
+        __bindgen_bitfield_unit.set(4usize, 4u8, {
+            let reserved: u8 = unsafe { ::core::mem::transmute(reserved) };
+            reserved as u64
+        });
+        __bindgen_bitfield_unit
+    }
+}
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct ip_v3 {
+    pub hw_id: u16,
+    pub instance_number: u8,
+    pub num_base_address: u8,
+    pub major: u8,
+    pub minor: u8,
+    pub revision: u8,
+    pub _bitfield_align_1: [u8; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 1usize]>,
+}
+impl ip_v3 {
+    #[inline]
+    pub fn sub_revision(&self) -> u8 {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 4u8) as u8) }
+    }
+    #[inline]
+    pub fn set_sub_revision(&mut self, val: u8) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            self._bitfield_1.set(0usize, 4u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn sub_revision_raw(this: *const Self) -> u8 {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 1usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                0usize,
+                4u8,
+            ) as u8)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_sub_revision_raw(this: *mut Self, val: u8) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 1usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                0usize,
+                4u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn variant(&self) -> u8 {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(4usize, 4u8) as u8) }
+    }
+    #[inline]
+    pub fn set_variant(&mut self, val: u8) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            self._bitfield_1.set(4usize, 4u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn variant_raw(this: *const Self) -> u8 {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 1usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                4usize,
+                4u8,
+            ) as u8)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_variant_raw(this: *mut Self, val: u8) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 1usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                4usize,
+                4u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn new_bitfield_1(
+        sub_revision: u8,
+        variant: u8,
+    ) -> __BindgenBitfieldUnit<[u8; 1usize]> {
+        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 1usize]> = Default::default();
+        __bindgen_bitfield_unit.set(0usize, 4u8, {
+            let sub_revision: u8 = unsafe { ::core::mem::transmute(sub_revision) };
+            sub_revision as u64
+        });
+        __bindgen_bitfield_unit.set(4usize, 4u8, {
+            let variant: u8 = unsafe { ::core::mem::transmute(variant) };
+            variant as u64
+        });
+        __bindgen_bitfield_unit
+    }
+}
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct ip_v4 {
+    pub hw_id: u16,
+    pub instance_number: u8,
+    pub num_base_address: u8,
+    pub major: u8,
+    pub minor: u8,
+    pub revision: u8,
+    pub _bitfield_align_1: [u8; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 1usize]>,
+}
+impl ip_v4 {
+    #[inline]
+    pub fn sub_revision(&self) -> u8 {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 4u8) as u8) }
+    }
+    #[inline]
+    pub fn set_sub_revision(&mut self, val: u8) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            self._bitfield_1.set(0usize, 4u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn sub_revision_raw(this: *const Self) -> u8 {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 1usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                0usize,
+                4u8,
+            ) as u8)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_sub_revision_raw(this: *mut Self, val: u8) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 1usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                0usize,
+                4u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn variant(&self) -> u8 {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(4usize, 4u8) as u8) }
+    }
+    #[inline]
+    pub fn set_variant(&mut self, val: u8) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            self._bitfield_1.set(4usize, 4u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn variant_raw(this: *const Self) -> u8 {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 1usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                4usize,
+                4u8,
+            ) as u8)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_variant_raw(this: *mut Self, val: u8) {
+        unsafe {
+            let val: u8 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 1usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                4usize,
+                4u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn new_bitfield_1(
+        sub_revision: u8,
+        variant: u8,
+    ) -> __BindgenBitfieldUnit<[u8; 1usize]> {
+        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 1usize]> = Default::default();
+        __bindgen_bitfield_unit.set(0usize, 4u8, {
+            let sub_revision: u8 = unsafe { ::core::mem::transmute(sub_revision) };
+            sub_revision as u64
+        });
+        __bindgen_bitfield_unit.set(4usize, 4u8, {
+            let variant: u8 = unsafe { ::core::mem::transmute(variant) };
+            variant as u64
+        });
+        __bindgen_bitfield_unit
+    }
+}
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct die_header {
+    pub die_id: u16,
+    pub num_ips: u16,
+}
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct harvest_info_header {
+    pub signature: u32,
+    pub version: u32,
+}
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct harvest_info {
+    pub hw_id: u16,
+    pub number_instance: u8,
+    pub reserved: u8,
+}
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct harvest_table {
+    pub header: harvest_info_header,
+    pub list: [harvest_info; 32usize],
+}
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct gpu_info_header {
+    pub table_id: u32,
+    pub version_major: u16,
+    pub version_minor: u16,
+    pub size: u32,
+}
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct gc_info_v1_0 {
+    pub header: gpu_info_header,
+    pub gc_num_se: u32,
+    pub gc_num_wgp0_per_sa: u32,
+    pub gc_num_wgp1_per_sa: u32,
+    pub gc_num_rb_per_se: u32,
+    pub gc_num_gl2c: u32,
+    pub gc_num_gprs: u32,
+    pub gc_num_max_gs_thds: u32,
+    pub gc_gs_table_depth: u32,
+    pub gc_gsprim_buff_depth: u32,
+    pub gc_parameter_cache_depth: u32,
+    pub gc_double_offchip_lds_buffer: u32,
+    pub gc_wave_size: u32,
+    pub gc_max_waves_per_simd: u32,
+    pub gc_max_scratch_slots_per_cu: u32,
+    pub gc_lds_size: u32,
+    pub gc_num_sc_per_se: u32,
+    pub gc_num_sa_per_se: u32,
+    pub gc_num_packer_per_sc: u32,
+    pub gc_num_gl2a: u32,
+}
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct gc_info_v1_1 {
+    pub header: gpu_info_header,
+    pub gc_num_se: u32,
+    pub gc_num_wgp0_per_sa: u32,
+    pub gc_num_wgp1_per_sa: u32,
+    pub gc_num_rb_per_se: u32,
+    pub gc_num_gl2c: u32,
+    pub gc_num_gprs: u32,
+    pub gc_num_max_gs_thds: u32,
+    pub gc_gs_table_depth: u32,
+    pub gc_gsprim_buff_depth: u32,
+    pub gc_parameter_cache_depth: u32,
+    pub gc_double_offchip_lds_buffer: u32,
+    pub gc_wave_size: u32,
+    pub gc_max_waves_per_simd: u32,
+    pub gc_max_scratch_slots_per_cu: u32,
+    pub gc_lds_size: u32,
+    pub gc_num_sc_per_se: u32,
+    pub gc_num_sa_per_se: u32,
+    pub gc_num_packer_per_sc: u32,
+    pub gc_num_gl2a: u32,
+    pub gc_num_tcp_per_sa: u32,
+    pub gc_num_sdp_interface: u32,
+    pub gc_num_tcps: u32,
+}
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct gc_info_v1_2 {
+    pub header: gpu_info_header,
+    pub gc_num_se: u32,
+    pub gc_num_wgp0_per_sa: u32,
+    pub gc_num_wgp1_per_sa: u32,
+    pub gc_num_rb_per_se: u32,
+    pub gc_num_gl2c: u32,
+    pub gc_num_gprs: u32,
+    pub gc_num_max_gs_thds: u32,
+    pub gc_gs_table_depth: u32,
+    pub gc_gsprim_buff_depth: u32,
+    pub gc_parameter_cache_depth: u32,
+    pub gc_double_offchip_lds_buffer: u32,
+    pub gc_wave_size: u32,
+    pub gc_max_waves_per_simd: u32,
+    pub gc_max_scratch_slots_per_cu: u32,
+    pub gc_lds_size: u32,
+    pub gc_num_sc_per_se: u32,
+    pub gc_num_sa_per_se: u32,
+    pub gc_num_packer_per_sc: u32,
+    pub gc_num_gl2a: u32,
+    pub gc_num_tcp_per_sa: u32,
+    pub gc_num_sdp_interface: u32,
+    pub gc_num_tcps: u32,
+    pub gc_num_tcp_per_wpg: u32,
+    pub gc_tcp_l1_size: u32,
+    pub gc_num_sqc_per_wgp: u32,
+    pub gc_l1_instruction_cache_size_per_sqc: u32,
+    pub gc_l1_data_cache_size_per_sqc: u32,
+    pub gc_gl1c_per_sa: u32,
+    pub gc_gl1c_size_per_instance: u32,
+    pub gc_gl2c_per_gpu: u32,
+}
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct gc_info_v1_3 {
+    pub header: gpu_info_header,
+    pub gc_num_se: u32,
+    pub gc_num_wgp0_per_sa: u32,
+    pub gc_num_wgp1_per_sa: u32,
+    pub gc_num_rb_per_se: u32,
+    pub gc_num_gl2c: u32,
+    pub gc_num_gprs: u32,
+    pub gc_num_max_gs_thds: u32,
+    pub gc_gs_table_depth: u32,
+    pub gc_gsprim_buff_depth: u32,
+    pub gc_parameter_cache_depth: u32,
+    pub gc_double_offchip_lds_buffer: u32,
+    pub gc_wave_size: u32,
+    pub gc_max_waves_per_simd: u32,
+    pub gc_max_scratch_slots_per_cu: u32,
+    pub gc_lds_size: u32,
+    pub gc_num_sc_per_se: u32,
+    pub gc_num_sa_per_se: u32,
+    pub gc_num_packer_per_sc: u32,
+    pub gc_num_gl2a: u32,
+    pub gc_num_tcp_per_sa: u32,
+    pub gc_num_sdp_interface: u32,
+    pub gc_num_tcps: u32,
+    pub gc_num_tcp_per_wpg: u32,
+    pub gc_tcp_l1_size: u32,
+    pub gc_num_sqc_per_wgp: u32,
+    pub gc_l1_instruction_cache_size_per_sqc: u32,
+    pub gc_l1_data_cache_size_per_sqc: u32,
+    pub gc_gl1c_per_sa: u32,
+    pub gc_gl1c_size_per_instance: u32,
+    pub gc_gl2c_per_gpu: u32,
+    pub gc_tcp_size_per_cu: u32,
+    pub gc_tcp_cache_line_size: u32,
+    pub gc_instruction_cache_size_per_sqc: u32,
+    pub gc_instruction_cache_line_size: u32,
+    pub gc_scalar_data_cache_size_per_sqc: u32,
+    pub gc_scalar_data_cache_line_size: u32,
+    pub gc_tcc_size: u32,
+    pub gc_tcc_cache_line_size: u32,
+}
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct gc_info_v2_0 {
+    pub header: gpu_info_header,
+    pub gc_num_se: u32,
+    pub gc_num_cu_per_sh: u32,
+    pub gc_num_sh_per_se: u32,
+    pub gc_num_rb_per_se: u32,
+    pub gc_num_tccs: u32,
+    pub gc_num_gprs: u32,
+    pub gc_num_max_gs_thds: u32,
+    pub gc_gs_table_depth: u32,
+    pub gc_gsprim_buff_depth: u32,
+    pub gc_parameter_cache_depth: u32,
+    pub gc_double_offchip_lds_buffer: u32,
+    pub gc_wave_size: u32,
+    pub gc_max_waves_per_simd: u32,
+    pub gc_max_scratch_slots_per_cu: u32,
+    pub gc_lds_size: u32,
+    pub gc_num_sc_per_se: u32,
+    pub gc_num_packer_per_sc: u32,
+}
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct gc_info_v2_1 {
+    pub header: gpu_info_header,
+    pub gc_num_se: u32,
+    pub gc_num_cu_per_sh: u32,
+    pub gc_num_sh_per_se: u32,
+    pub gc_num_rb_per_se: u32,
+    pub gc_num_tccs: u32,
+    pub gc_num_gprs: u32,
+    pub gc_num_max_gs_thds: u32,
+    pub gc_gs_table_depth: u32,
+    pub gc_gsprim_buff_depth: u32,
+    pub gc_parameter_cache_depth: u32,
+    pub gc_double_offchip_lds_buffer: u32,
+    pub gc_wave_size: u32,
+    pub gc_max_waves_per_simd: u32,
+    pub gc_max_scratch_slots_per_cu: u32,
+    pub gc_lds_size: u32,
+    pub gc_num_sc_per_se: u32,
+    pub gc_num_packer_per_sc: u32,
+    pub gc_num_tcp_per_sh: u32,
+    pub gc_tcp_size_per_cu: u32,
+    pub gc_num_sdp_interface: u32,
+    pub gc_num_cu_per_sqc: u32,
+    pub gc_instruction_cache_size_per_sqc: u32,
+    pub gc_scalar_data_cache_size_per_sqc: u32,
+    pub gc_tcc_size: u32,
+}
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct mall_info_v1_0 {
+    pub header: gpu_info_header,
+    pub mall_size_per_m: u32,
+    pub m_s_present: u32,
+    pub m_half_use: u32,
+    pub m_mall_config: u32,
+    pub reserved: [u32; 5usize],
+}
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct mall_info_v2_0 {
+    pub header: gpu_info_header,
+    pub mall_size_per_umc: u32,
+    pub reserved: [u32; 8usize],
+}
+pub const VCN_INFO_TABLE_MAX_NUM_INSTANCES: u32 = 4;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct vcn_info_header {
+    pub table_id: u32,
+    pub version_major: u16,
+    pub version_minor: u16,
+    pub size_bytes: u32,
+}
+#[repr(C, packed)]
+#[derive(Copy, Clone)]
+pub struct vcn_instance_info_v1_0 {
+    pub instance_num: u32,
+    pub fuse_data: vcn_instance_info_v1_0__fuse_data,
+    pub reserved: [u32; 2usize],
+}
+#[repr(C, packed)]
+#[derive(Copy, Clone)]
+pub union vcn_instance_info_v1_0__fuse_data {
+    pub bits: vcn_instance_info_v1_0__fuse_data__bindgen_ty_1,
+    pub all_bits: u32,
+}
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct vcn_instance_info_v1_0__fuse_data__bindgen_ty_1 {
+    pub _bitfield_align_1: [u8; 0],
+    pub _bitfield_1: __BindgenBitfieldUnit<[u8; 4usize]>,
+}
+impl vcn_instance_info_v1_0__fuse_data__bindgen_ty_1 {
+    #[inline]
+    pub fn av1_disabled(&self) -> u32 {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(0usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set_av1_disabled(&mut self, val: u32) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(0usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn av1_disabled_raw(this: *const Self) -> u32 {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 4usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                0usize,
+                1u8,
+            ) as u32)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_av1_disabled_raw(this: *mut Self, val: u32) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 4usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                0usize,
+                1u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn vp9_disabled(&self) -> u32 {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(1usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set_vp9_disabled(&mut self, val: u32) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(1usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn vp9_disabled_raw(this: *const Self) -> u32 {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 4usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                1usize,
+                1u8,
+            ) as u32)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_vp9_disabled_raw(this: *mut Self, val: u32) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 4usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                1usize,
+                1u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn hevc_disabled(&self) -> u32 {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(2usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set_hevc_disabled(&mut self, val: u32) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(2usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn hevc_disabled_raw(this: *const Self) -> u32 {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 4usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                2usize,
+                1u8,
+            ) as u32)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_hevc_disabled_raw(this: *mut Self, val: u32) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 4usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                2usize,
+                1u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn h264_disabled(&self) -> u32 {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(3usize, 1u8) as u32) }
+    }
+    #[inline]
+    pub fn set_h264_disabled(&mut self, val: u32) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(3usize, 1u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn h264_disabled_raw(this: *const Self) -> u32 {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 4usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                3usize,
+                1u8,
+            ) as u32)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_h264_disabled_raw(this: *mut Self, val: u32) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 4usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                3usize,
+                1u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn reserved(&self) -> u32 {
+        unsafe { ::core::mem::transmute(self._bitfield_1.get(4usize, 28u8) as u32) }
+    }
+    #[inline]
+    pub fn set_reserved(&mut self, val: u32) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            self._bitfield_1.set(4usize, 28u8, val as u64)
+        }
+    }
+    #[inline]
+    pub unsafe fn reserved_raw(this: *const Self) -> u32 {
+        unsafe {
+            ::core::mem::transmute(<__BindgenBitfieldUnit<[u8; 4usize]>>::raw_get(
+                ::core::ptr::addr_of!((*this)._bitfield_1),
+                4usize,
+                28u8,
+            ) as u32)
+        }
+    }
+    #[inline]
+    pub unsafe fn set_reserved_raw(this: *mut Self, val: u32) {
+        unsafe {
+            let val: u32 = ::core::mem::transmute(val);
+            <__BindgenBitfieldUnit<[u8; 4usize]>>::raw_set(
+                ::core::ptr::addr_of_mut!((*this)._bitfield_1),
+                4usize,
+                28u8,
+                val as u64,
+            )
+        }
+    }
+    #[inline]
+    pub fn new_bitfield_1(
+        av1_disabled: u32,
+        vp9_disabled: u32,
+        hevc_disabled: u32,
+        h264_disabled: u32,
+        reserved: u32,
+    ) -> __BindgenBitfieldUnit<[u8; 4usize]> {
+        let mut __bindgen_bitfield_unit: __BindgenBitfieldUnit<[u8; 4usize]> = Default::default();
+        __bindgen_bitfield_unit.set(0usize, 1u8, {
+            let av1_disabled: u32 = unsafe { ::core::mem::transmute(av1_disabled) };
+            av1_disabled as u64
+        });
+        __bindgen_bitfield_unit.set(1usize, 1u8, {
+            let vp9_disabled: u32 = unsafe { ::core::mem::transmute(vp9_disabled) };
+            vp9_disabled as u64
+        });
+        __bindgen_bitfield_unit.set(2usize, 1u8, {
+            let hevc_disabled: u32 = unsafe { ::core::mem::transmute(hevc_disabled) };
+            hevc_disabled as u64
+        });
+        __bindgen_bitfield_unit.set(3usize, 1u8, {
+            let h264_disabled: u32 = unsafe { ::core::mem::transmute(h264_disabled) };
+            h264_disabled as u64
+        });
+        __bindgen_bitfield_unit.set(4usize, 28u8, {
+            let reserved: u32 = unsafe { ::core::mem::transmute(reserved) };
+            reserved as u64
+        });
+        __bindgen_bitfield_unit
+    }
+}
+#[repr(C, packed)]
+#[derive(Copy, Clone)]
+pub struct vcn_info_v1_0 {
+    pub header: vcn_info_header,
+    pub num_of_instances: u32,
+    pub instance_info: [vcn_instance_info_v1_0; 4usize],
+    pub reserved: [u32; 4usize],
+}
@@ -0,0 +1,46 @@
+use crate::bindings::atom_common_table_header;
+/* `PPTable_t` of smu14_driver_if_v14_0.h is not included, see [PPTable::smc_pptable_offset] */
+pub type PPTable_t = [u8; 0];
+
+pub const SMU_14_0_2_TABLE_FORMAT_REVISION: u32 = 3;
+pub const SMU_14_0_2_MAX_ODFEATURE: u32 = 32;
+pub const SMU_14_0_2_MAX_PMSETTING: u32 = 32;
+pub const SMU_14_0_2_MAX_ODSETTING: u32 = 64;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct smu_14_0_2_overdrive_table {
+    pub revision: u8,
+    pub reserve: [u8; 3usize],
+    pub cap: [u8; 32usize],
+    pub max: [i32; 64usize],
+    pub min: [i32; 64usize],
+    pub pm_setting: [i16; 32usize],
+}
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct smu_14_0_2_powerplay_table {
+    pub header: atom_common_table_header,
+    pub table_revision: u8,
+    pub padding: u8,
+    pub pmfw_pptable_start_offset: u16,
+    pub pmfw_pptable_size: u16,
+    pub pmfw_sku_table_start_offset: u16,
+    pub pmfw_sku_table_size: u16,
+    pub pmfw_board_table_start_offset: u16,
+    pub pmfw_board_table_size: u16,
+    pub pmfw_custom_sku_table_start_offset: u16,
+    pub pmfw_custom_sku_table_size: u16,
+    pub golden_pp_id: u32,
+    pub golden_revision: u32,
+    pub format_id: u16,
+    pub platform_caps: u32,
+    pub thermal_controller_type: u8,
+    pub small_power_limit1: u16,
+    pub small_power_limit2: u16,
+    pub boost_power_limit: u16,
+    pub software_shutdown_temp: u16,
+    pub reserve: [u8; 143usize],
+    pub overdrive_table: smu_14_0_2_overdrive_table,
+    pub padding1: u8,
+    pub smc_pptable: PPTable_t,
+}
@@ -0,0 +1,102 @@
+use super::super::atombios::ATOM_COMMON_TABLE_HEADER;
+
+pub type USHORT = u16;
+pub type ULONG = u32;
+pub type UCHAR = u8;
+pub const ATOM_Tonga_TABLE_REVISION_TONGA: u32 = 7;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ATOM_Tonga_POWERPLAYTABLE {
+    pub sHeader: ATOM_COMMON_TABLE_HEADER,
+    pub ucTableRevision: UCHAR,
+    pub usTableSize: USHORT,
+    pub ulGoldenPPID: ULONG,
+    pub ulGoldenRevision: ULONG,
+    pub usFormatID: USHORT,
+    pub usVoltageTime: USHORT,
+    pub ulPlatformCaps: ULONG,
+    pub ulMaxODEngineClock: ULONG,
+    pub ulMaxODMemoryClock: ULONG,
+    pub usPowerControlLimit: USHORT,
+    pub usUlvVoltageOffset: USHORT,
+    pub usStateArrayOffset: USHORT,
+    pub usFanTableOffset: USHORT,
+    pub usThermalControllerOffset: USHORT,
+    pub usReserv: USHORT,
+    pub usMclkDependencyTableOffset: USHORT,
+    pub usSclkDependencyTableOffset: USHORT,
+    pub usVddcLookupTableOffset: USHORT,
+    pub usVddgfxLookupTableOffset: USHORT,
+    pub usMMDependencyTableOffset: USHORT,
+    pub usVCEStateTableOffset: USHORT,
+    pub usPPMTableOffset: USHORT,
+    pub usPowerTuneTableOffset: USHORT,
+    pub usHardLimitTableOffset: USHORT,
+    pub usPCIETableOffset: USHORT,
+    pub usGPIOTableOffset: USHORT,
+    pub usReserved: [USHORT; 6usize],
+}
+pub type ATOM_Tonga_POWERPLAYTABLE = _ATOM_Tonga_POWERPLAYTABLE;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ATOM_Tonga_MCLK_Dependency_Record {
+    pub ucVddcInd: UCHAR,
+    pub usVddci: USHORT,
+    pub usVddgfxOffset: USHORT,
+    pub usMvdd: USHORT,
+    pub ulMclk: ULONG,
+    pub usReserved: USHORT,
+}
+pub type ATOM_Tonga_MCLK_Dependency_Record = _ATOM_Tonga_MCLK_Dependency_Record;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ATOM_Tonga_SCLK_Dependency_Record {
+    pub ucVddInd: UCHAR,
+    pub usVddcOffset: USHORT,
+    pub ulSclk: ULONG,
+    pub usEdcCurrent: USHORT,
+    pub ucReliabilityTemperature: UCHAR,
+    pub ucCKSVOffsetandDisable: UCHAR,
+}
+pub type ATOM_Tonga_SCLK_Dependency_Record = _ATOM_Tonga_SCLK_Dependency_Record;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ATOM_Polaris_SCLK_Dependency_Record {
+    pub ucVddInd: UCHAR,
+    pub usVddcOffset: USHORT,
+    pub ulSclk: ULONG,
+    pub usEdcCurrent: USHORT,
+    pub ucReliabilityTemperature: UCHAR,
+    pub ucCKSVOffsetandDisable: UCHAR,
+    pub ulSclkOffset: ULONG,
+}
+pub type ATOM_Polaris_SCLK_Dependency_Record = _ATOM_Polaris_SCLK_Dependency_Record;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ATOM_Tonga_Voltage_Lookup_Record {
+    pub usVdd: USHORT,
+    pub usCACLow: USHORT,
+    pub usCACMid: USHORT,
+    pub usCACHigh: USHORT,
+}
+pub type ATOM_Tonga_Voltage_Lookup_Record = _ATOM_Tonga_Voltage_Lookup_Record;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ATOM_Tonga_PowerTune_Table {
+    pub ucRevId: UCHAR,
+    pub usTDP: USHORT,
+    pub usConfigurableTDP: USHORT,
+    pub usTDC: USHORT,
+    pub usBatteryPowerLimit: USHORT,
+    pub usSmallPowerLimit: USHORT,
+    pub usLowCACLeakage: USHORT,
+    pub usHighCACLeakage: USHORT,
+    pub usMaximumPowerDeliveryLimit: USHORT,
+    pub usTjMax: USHORT,
+    pub usPowerTuneDataSetID: USHORT,
+    pub usEDCLimit: USHORT,
+    pub usSoftwareShutdownTemp: USHORT,
+    pub usClockStretchAmount: USHORT,
+    pub usReserve: [USHORT; 2usize],
+}
+pub type ATOM_Tonga_PowerTune_Table = _ATOM_Tonga_PowerTune_Table;
@@ -0,0 +1,125 @@
+use crate::bindings::atom_common_table_header;
+
+pub type USHORT = u16;
+pub type ULONG = u32;
+pub type UCHAR = u8;
+pub const ATOM_Vega10_TABLE_REVISION_VEGA10: u32 = 8;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ATOM_Vega10_POWERPLAYTABLE {
+    pub sHeader: atom_common_table_header,
+    pub ucTableRevision: UCHAR,
+    pub usTableSize: USHORT,
+    pub ulGoldenPPID: ULONG,
+    pub ulGoldenRevision: ULONG,
+    pub usFormatID: USHORT,
+    pub ulPlatformCaps: ULONG,
+    pub ulMaxODEngineClock: ULONG,
+    pub ulMaxODMemoryClock: ULONG,
+    pub usPowerControlLimit: USHORT,
+    pub usUlvVoltageOffset: USHORT,
+    pub usUlvSmnclkDid: USHORT,
+    pub usUlvMp1clkDid: USHORT,
+    pub usUlvGfxclkBypass: USHORT,
+    pub usGfxclkSlewRate: USHORT,
+    pub ucGfxVoltageMode: UCHAR,
+    pub ucSocVoltageMode: UCHAR,
+    pub ucUclkVoltageMode: UCHAR,
+    pub ucUvdVoltageMode: UCHAR,
+    pub ucVceVoltageMode: UCHAR,
+    pub ucMp0VoltageMode: UCHAR,
+    pub ucDcefVoltageMode: UCHAR,
+    pub usStateArrayOffset: USHORT,
+    pub usFanTableOffset: USHORT,
+    pub usThermalControllerOffset: USHORT,
+    pub usSocclkDependencyTableOffset: USHORT,
+    pub usMclkDependencyTableOffset: USHORT,
+    pub usGfxclkDependencyTableOffset: USHORT,
+    pub usDcefclkDependencyTableOffset: USHORT,
+    pub usVddcLookupTableOffset: USHORT,
+    pub usVddmemLookupTableOffset: USHORT,
+    pub usMMDependencyTableOffset: USHORT,
+    pub usVCEStateTableOffset: USHORT,
+    pub usReserve: USHORT,
+    pub usPowerTuneTableOffset: USHORT,
+    pub usHardLimitTableOffset: USHORT,
+    pub usVddciLookupTableOffset: USHORT,
+    pub usPCIETableOffset: USHORT,
+    pub usPixclkDependencyTableOffset: USHORT,
+    pub usDispClkDependencyTableOffset: USHORT,
+    pub usPhyClkDependencyTableOffset: USHORT,
+}
+pub type ATOM_Vega10_POWERPLAYTABLE = _ATOM_Vega10_POWERPLAYTABLE;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ATOM_Vega10_CLK_Dependency_Record {
+    pub ulClk: ULONG,
+    pub ucVddInd: UCHAR,
+}
+pub type ATOM_Vega10_CLK_Dependency_Record = _ATOM_Vega10_CLK_Dependency_Record;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ATOM_Vega10_GFXCLK_Dependency_Record {
+    pub ulClk: ULONG,
+    pub ucVddInd: UCHAR,
+    pub usCKSVOffsetandDisable: USHORT,
+    pub usAVFSOffset: USHORT,
+}
+pub type ATOM_Vega10_GFXCLK_Dependency_Record = _ATOM_Vega10_GFXCLK_Dependency_Record;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ATOM_Vega10_GFXCLK_Dependency_Record_V2 {
+    pub ulClk: ULONG,
+    pub ucVddInd: UCHAR,
+    pub usCKSVOffsetandDisable: USHORT,
+    pub usAVFSOffset: USHORT,
+    pub ucACGEnable: UCHAR,
+    pub ucReserved: [UCHAR; 3usize],
+}
+pub type ATOM_Vega10_GFXCLK_Dependency_Record_V2 = _ATOM_Vega10_GFXCLK_Dependency_Record_V2;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ATOM_Vega10_MCLK_Dependency_Record {
+    pub ulMemClk: ULONG,
+    pub ucVddInd: UCHAR,
+    pub ucVddMemInd: UCHAR,
+    pub ucVddciInd: UCHAR,
+}
+pub type ATOM_Vega10_MCLK_Dependency_Record = _ATOM_Vega10_MCLK_Dependency_Record;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ATOM_Vega10_Voltage_Lookup_Record {
+    pub usVdd: USHORT,
+}
+pub type ATOM_Vega10_Voltage_Lookup_Record = _ATOM_Vega10_Voltage_Lookup_Record;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ATOM_Vega10_PowerTune_Table {
+    pub ucRevId: UCHAR,
+    pub usSocketPowerLimit: USHORT,
+    pub usBatteryPowerLimit: USHORT,
+    pub usSmallPowerLimit: USHORT,
+    pub usTdcLimit: USHORT,
+    pub usEdcLimit: USHORT,
+    pub usSoftwareShutdownTemp: USHORT,
+    pub usTemperatureLimitHotSpot: USHORT,
+    pub usTemperatureLimitLiquid1: USHORT,
+    pub usTemperatureLimitLiquid2: USHORT,
+    pub usTemperatureLimitHBM: USHORT,
+    pub usTemperatureLimitVrSoc: USHORT,
+    pub usTemperatureLimitVrMem: USHORT,
+    pub usTemperatureLimitPlx: USHORT,
+    pub usLoadLineResistance: USHORT,
+    pub ucLiquid1_I2C_address: UCHAR,
+    pub ucLiquid2_I2C_address: UCHAR,
+    pub ucVr_I2C_address: UCHAR,
+    pub ucPlx_I2C_address: UCHAR,
+    pub ucLiquid_I2C_LineSCL: UCHAR,
+    pub ucLiquid_I2C_LineSDA: UCHAR,
+    pub ucVr_I2C_LineSCL: UCHAR,
+    pub ucVr_I2C_LineSDA: UCHAR,
+    pub ucPlx_I2C_LineSCL: UCHAR,
+    pub ucPlx_I2C_LineSDA: UCHAR,
+    pub usTemperatureLimitTedge: USHORT,
+}
+pub type ATOM_Vega10_PowerTune_Table = _ATOM_Vega10_PowerTune_Table;
@@ -0,0 +1,54 @@
+use crate::bindings::atom_common_table_header;
+/* `PPTable_t` of smu11_driver_if.h is not included, see [PPTable::smc_pptable_offset] */
+pub type PPTable_t = [u8; 0];
+
+pub type USHORT = u16;
+pub type ULONG = u32;
+pub type UCHAR = u8;
+pub const ATOM_VEGA20_TABLE_REVISION_VEGA20: u32 = 11;
+pub const ATOM_VEGA20_ODFEATURE_MAX_COUNT: u32 = 32;
+pub const ATOM_VEGA20_ODSETTING_MAX_COUNT: u32 = 32;
+pub const ATOM_VEGA20_PPCLOCK_MAX_COUNT: u32 = 16;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ATOM_VEGA20_OVERDRIVE8_RECORD {
+    pub ucODTableRevision: UCHAR,
+    pub ODFeatureCount: ULONG,
+    pub ODFeatureCapabilities: [UCHAR; 32usize],
+    pub ODSettingCount: ULONG,
+    pub ODSettingsMax: [ULONG; 32usize],
+    pub ODSettingsMin: [ULONG; 32usize],
+}
+pub type ATOM_VEGA20_OVERDRIVE8_RECORD = _ATOM_VEGA20_OVERDRIVE8_RECORD;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ATOM_VEGA20_POWER_SAVING_CLOCK_RECORD {
+    pub ucTableRevision: UCHAR,
+    pub PowerSavingClockCount: ULONG,
+    pub PowerSavingClockMax: [ULONG; 16usize],
+    pub PowerSavingClockMin: [ULONG; 16usize],
+}
+pub type ATOM_VEGA20_POWER_SAVING_CLOCK_RECORD = _ATOM_VEGA20_POWER_SAVING_CLOCK_RECORD;
+#[repr(C, packed)]
+#[derive(Debug, Copy, Clone)]
+pub struct _ATOM_VEGA20_POWERPLAYTABLE {
+    pub sHeader: atom_common_table_header,
+    pub ucTableRevision: UCHAR,
+    pub usTableSize: USHORT,
+    pub ulGoldenPPID: ULONG,
+    pub ulGoldenRevision: ULONG,
+    pub usFormatID: USHORT,
+    pub ulPlatformCaps: ULONG,
+    pub ucThermalControllerType: UCHAR,
+    pub usSmallPowerLimit1: USHORT,
+    pub usSmallPowerLimit2: USHORT,
+    pub usBoostPowerLimit: USHORT,
+    pub usODTurboPowerLimit: USHORT,
+    pub usODPowerSavePowerLimit: USHORT,
+    pub usSoftwareShutdownTemp: USHORT,
+    pub PowerSavingClockTable: ATOM_VEGA20_POWER_SAVING_CLOCK_RECORD,
+    pub OverDrive8Table: ATOM_VEGA20_OVERDRIVE8_RECORD,
+    pub usReserve: [USHORT; 5usize],
+    pub smcPPTable: PPTable_t,
+}
+pub type ATOM_Vega20_POWERPLAYTABLE = _ATOM_VEGA20_POWERPLAYTABLE;
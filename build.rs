@@ -174,6 +174,125 @@ fn build() {
             .expect("Couldn't write bindings!");
     }
 
+    {
+        let atombios = bindgen::Builder::default()
+            .header("wrapper/wrapper_atombios.h")
+            .allowlist_type("ATOM_ROM_HEADER")
+            .allowlist_type("ATOM_ROM_HEADER_V2_1")
+            .allowlist_type("ATOM_MASTER_DATA_TABLE")
+            .allowlist_type("ATOM_FIRMWARE_INFO_V2_1")
+            .allowlist_type("ATOM_FIRMWARE_INFO_V2_2")
+            .blocklist_type("_?ATOM_FIRMWARE_CAPABILITY_ACCESS")
+            .raw_line("/* a union of the bitfields and `susAccess`, use `usFirmwareCapability` as `USHORT` */")
+            .raw_line("pub type ATOM_FIRMWARE_CAPABILITY_ACCESS = USHORT;")
+            .layout_tests(false)
+            .use_core()
+            .ctypes_prefix("::core::ffi")
+            .generate()
+            .expect("Unable to generate bindings: {wrapper_name}");
+
+        atombios
+            .write_to_file(out_path.join("atombios.rs"))
+            .expect("Couldn't write bindings!");
+    }
+
+    {
+        let tonga_ppt = bindgen::Builder::default()
+            .header("wrapper/wrapper_atombios.h")
+            .header("wrapper/tonga_pptable.h")
+            .allowlist_file(".*tonga_pptable.h")
+            .blocklist_type("_?ATOM_COMMON_TABLE_HEADER")
+            .raw_line("use super::super::atombios::ATOM_COMMON_TABLE_HEADER;")
+            .layout_tests(false)
+            .use_core()
+            .ctypes_prefix("::core::ffi")
+            .generate()
+            .expect("Unable to generate bindings: {wrapper_name}");
+
+        tonga_ppt
+            .write_to_file(out_path.join("ppt").join("tonga_ppt.rs"))
+            .expect("Couldn't write bindings!");
+    }
+
+    {
+        let vega10_ppt = bindgen::Builder::default()
+            .header("wrapper/wrapper_atomfirmware.h")
+            .header("wrapper/wrapper_atombios_types.h")
+            .header("wrapper/vega10_pptable.h")
+            .allowlist_file(".*vega10_pptable.h")
+            .blocklist_type("atom_common_table_header")
+            .raw_line("use crate::bindings::atom_common_table_header;")
+            .layout_tests(false)
+            .use_core()
+            .ctypes_prefix("::core::ffi")
+            .generate()
+            .expect("Unable to generate bindings: {wrapper_name}");
+
+        vega10_ppt
+            .write_to_file(out_path.join("ppt").join("vega10_ppt.rs"))
+            .expect("Couldn't write bindings!");
+    }
+
+    {
+        let vega20_ppt = bindgen::Builder::default()
+            .header("wrapper/wrapper_atomfirmware.h")
+            .header("wrapper/wrapper_atombios_types.h")
+            .header("wrapper/wrapper_smc_pptable.h")
+            .header("wrapper/vega20_pptable.h")
+            .allowlist_file(".*vega20_pptable.h")
+            .blocklist_type("atom_common_table_header")
+            .blocklist_type("PPTable_t")
+            .raw_line("use crate::bindings::atom_common_table_header;")
+            .raw_line("/* `PPTable_t` of smu11_driver_if.h is not included, see [PPTable::smc_pptable_offset] */")
+            .raw_line("pub type PPTable_t = [u8; 0];")
+            .layout_tests(false)
+            .use_core()
+            .ctypes_prefix("::core::ffi")
+            .generate()
+            .expect("Unable to generate bindings: {wrapper_name}");
+
+        vega20_ppt
+            .write_to_file(out_path.join("ppt").join("vega20_ppt.rs"))
+            .expect("Couldn't write bindings!");
+    }
+
+    {
+        let smu_v14_0_2_ppt = bindgen::Builder::default()
+            .header("wrapper/wrapper_atomfirmware.h")
+            .header("wrapper/wrapper_smc_pptable.h")
+            .header("wrapper/smu_v14_0_2_pptable.h")
+            .allowlist_file(".*smu_v14_0_2_pptable.h")
+            .blocklist_type("atom_common_table_header")
+            .blocklist_type("PPTable_t")
+            .raw_line("use crate::bindings::atom_common_table_header;")
+            .raw_line("/* `PPTable_t` of smu14_driver_if_v14_0.h is not included, see [PPTable::smc_pptable_offset] */")
+            .raw_line("pub type PPTable_t = [u8; 0];")
+            .layout_tests(false)
+            .use_core()
+            .ctypes_prefix("::core::ffi")
+            .generate()
+            .expect("Unable to generate bindings: {wrapper_name}");
+
+        smu_v14_0_2_ppt
+            .write_to_file(out_path.join("ppt").join("smu_v14_0_2_ppt.rs"))
+            .expect("Couldn't write bindings!");
+    }
+
+    {
+        let discovery = bindgen::Builder::default()
+            .header("wrapper/discovery.h")
+            .allowlist_file(".*discovery.h")
+            .layout_tests(false)
+            .use_core()
+            .ctypes_prefix("::core::ffi")
+            .generate()
+            .expect("Unable to generate bindings: {wrapper_name}");
+
+        discovery
+            .write_to_file(out_path.join("discovery.rs"))
+            .expect("Couldn't write bindings!");
+    }
+
     convert_amdgpu_ids();
 }
 
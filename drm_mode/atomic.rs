@@ -0,0 +1,194 @@
+use crate::{bindings, LibDrm};
+use std::collections::HashMap;
+use std::fmt;
+
+pub use bindings::{
+    drmModeAtomicReqPtr,
+    DRM_MODE_ATOMIC_TEST_ONLY,
+    DRM_MODE_ATOMIC_NONBLOCK,
+    DRM_MODE_ATOMIC_ALLOW_MODESET,
+    DRM_MODE_OBJECT_CRTC,
+    DRM_MODE_OBJECT_CONNECTOR,
+    DRM_MODE_OBJECT_PLANE,
+};
+
+/// Builder for an atomic modesetting request (`drmModeAtomicReq`).
+/// Properties are set by name, and resolved to the property IDs of each object.
+/// `DRM_CLIENT_CAP_ATOMIC` must be enabled first (e.g. [LibDrm::set_all_client_caps]).
+pub struct AtomicRequest {
+    pub(crate) ptr: drmModeAtomicReqPtr,
+    pub(crate) lib: LibDrm,
+    flags: u32,
+    /// object_id -> [(property name, property id)]
+    prop_ids: HashMap<u32, Vec<(String, u32)>>,
+    /// (object_id, property name) in the order of addition
+    props: Vec<(u32, String)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AtomicRequestError {
+    AllocFailed,
+    /// The object has no property with the name
+    PropertyNotFound { object_id: u32, name: String },
+    /// `drmModeAtomicAddProperty` returned the error
+    AddProperty { object_id: u32, name: String, errno: i32 },
+    /// `drmModeAtomicCommit` returned the error.
+    /// The kernel does not report which property was rejected.
+    Commit { errno: i32 },
+}
+
+impl fmt::Display for AtomicRequestError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::AllocFailed => write!(f, "drmModeAtomicAlloc failed"),
+            Self::PropertyNotFound { object_id, name } =>
+                write!(f, "property {name:?} not found (object: {object_id})"),
+            Self::AddProperty { object_id, name, errno } =>
+                write!(f, "failed to add property {name:?} (object: {object_id}, errno: {errno})"),
+            Self::Commit { errno } =>
+                write!(f, "atomic commit failed (errno: {errno})"),
+        }
+    }
+}
+
+impl std::error::Error for AtomicRequestError {}
+
+impl LibDrm {
+    pub fn atomic_request(&self) -> Result<AtomicRequest, AtomicRequestError> {
+        #[cfg(feature = "link_drm")]
+        let func = bindings::drmModeAtomicAlloc;
+        #[cfg(feature = "dynamic_loading")]
+        let func = self.libdrm.drmModeAtomicAlloc;
+
+        let ptr = unsafe { func() };
+
+        if ptr.is_null() { return Err(AtomicRequestError::AllocFailed); }
+
+        Ok(AtomicRequest {
+            ptr,
+            lib: self.clone(),
+            flags: 0,
+            prop_ids: HashMap::new(),
+            props: Vec::new(),
+        })
+    }
+}
+
+impl AtomicRequest {
+    #[cfg(feature = "link_drm")]
+    pub fn new() -> Result<Self, AtomicRequestError> {
+        LibDrm::new().unwrap().atomic_request()
+    }
+
+    /// `DRM_MODE_ATOMIC_TEST_ONLY`, check the request without applying it
+    pub fn test_only(&mut self, enable: bool) -> &mut Self {
+        self.set_flag(DRM_MODE_ATOMIC_TEST_ONLY, enable)
+    }
+
+    /// `DRM_MODE_ATOMIC_ALLOW_MODESET`
+    pub fn allow_modeset(&mut self, enable: bool) -> &mut Self {
+        self.set_flag(DRM_MODE_ATOMIC_ALLOW_MODESET, enable)
+    }
+
+    /// `DRM_MODE_ATOMIC_NONBLOCK`
+    pub fn nonblock(&mut self, enable: bool) -> &mut Self {
+        self.set_flag(DRM_MODE_ATOMIC_NONBLOCK, enable)
+    }
+
+    fn set_flag(&mut self, flag: u32, enable: bool) -> &mut Self {
+        if enable {
+            self.flags |= flag;
+        } else {
+            self.flags &= !flag;
+        }
+
+        self
+    }
+
+    pub fn flags(&self) -> u32 {
+        self.flags
+    }
+
+    /// Number of the added properties
+    pub fn len(&self) -> usize {
+        self.props.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.props.is_empty()
+    }
+
+    /// Add the property by name.
+    /// `object_type` is `DRM_MODE_OBJECT_*` (CRTC, CONNECTOR, PLANE, ...).
+    pub fn add_property(
+        &mut self,
+        fd: i32,
+        object_id: u32,
+        object_type: u32,
+        name: &str,
+        value: u64,
+    ) -> Result<&mut Self, AtomicRequestError> {
+        let prop_id = self.find_property_id(fd, object_id, object_type, name)
+            .ok_or_else(|| AtomicRequestError::PropertyNotFound { object_id, name: name.to_string() })?;
+
+        self.add_property_id(object_id, prop_id, name, value)
+    }
+
+    fn add_property_id(
+        &mut self,
+        object_id: u32,
+        prop_id: u32,
+        name: &str,
+        value: u64,
+    ) -> Result<&mut Self, AtomicRequestError> {
+        #[cfg(feature = "link_drm")]
+        let func = bindings::drmModeAtomicAddProperty;
+        #[cfg(feature = "dynamic_loading")]
+        let func = self.lib.libdrm.drmModeAtomicAddProperty;
+
+        let r = unsafe { func(self.ptr, object_id, prop_id, value) };
+
+        if r < 0 {
+            return Err(AtomicRequestError::AddProperty { object_id, name: name.to_string(), errno: -r });
+        }
+
+        self.props.push((object_id, name.to_string()));
+
+        Ok(self)
+    }
+
+    fn find_property_id(&mut self, fd: i32, object_id: u32, object_type: u32, name: &str) -> Option<u32> {
+        if !self.prop_ids.contains_key(&object_id) {
+            let props = self.lib.get_drm_mode_object_properties(fd, object_id, object_type)?;
+            let ids = props.get_mode_property(fd).iter().map(|(prop, _)| (prop.name(), prop.prop_id())).collect();
+
+            self.prop_ids.insert(object_id, ids);
+        }
+
+        self.prop_ids.get(&object_id)?.iter().find(|(n, _)| n == name).map(|(_, id)| *id)
+    }
+
+    pub fn commit(&self, fd: i32) -> Result<(), AtomicRequestError> {
+        #[cfg(feature = "link_drm")]
+        let func = bindings::drmModeAtomicCommit;
+        #[cfg(feature = "dynamic_loading")]
+        let func = self.lib.libdrm.drmModeAtomicCommit;
+
+        let r = unsafe { func(fd, self.ptr, self.flags, std::ptr::null_mut()) };
+
+        if r < 0 { return Err(AtomicRequestError::Commit { errno: -r }) }
+
+        Ok(())
+    }
+}
+
+impl Drop for AtomicRequest {
+    fn drop(&mut self) {
+        #[cfg(feature = "link_drm")]
+        let func = bindings::drmModeAtomicFree;
+        #[cfg(feature = "dynamic_loading")]
+        let func = self.lib.libdrm.drmModeAtomicFree;
+
+	    unsafe { func(self.ptr); }
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{bindings, drmModeObjectProperties, drmModeRes, LibDrm};
+use bindings::drmModeEncoderPtr;
+pub use bindings::drmModeEncoder;
+
+#[allow(dead_code)]
+#[derive(Clone)]
+struct WrapperDrmModeEncoderPtr {
+    pub(crate) ptr: drmModeEncoderPtr,
+    pub(crate) lib: LibDrm,
+}
+
+impl LibDrm {
+    pub fn get_drm_mode_encoder(&self, fd: i32, encoder_id: u32) -> Option<drmModeEncoder> {
+        #[cfg(feature = "link_drm")]
+        let func = bindings::drmModeGetEncoder;
+        #[cfg(feature = "dynamic_loading")]
+        let func = self.libdrm.drmModeGetEncoder;
+
+        let ptr = unsafe { func(fd, encoder_id) };
+
+        if ptr.is_null() { return None; }
+
+        let wrapper = WrapperDrmModeEncoderPtr { ptr, lib: self.clone() };
+
+        Some(unsafe { wrapper.ptr.read() })
+    }
+
+    pub fn get_drm_mode_encoder_props(
+        &self,
+        fd: i32,
+        encoder_id: u32,
+    ) -> Option<drmModeObjectProperties> {
+        self.get_drm_mode_object_properties(
+            fd,
+            encoder_id,
+            bindings::DRM_MODE_OBJECT_ENCODER,
+        )
+    }
+}
+
+impl drmModeEncoder {
+    #[cfg(feature = "link_drm")]
+    pub fn get(fd: i32, encoder_id: u32) -> Option<Self> {
+        let ptr = unsafe { bindings::drmModeGetEncoder(fd, encoder_id) };
+
+        if ptr.is_null() { return None; }
+
+        let wrapper = WrapperDrmModeEncoderPtr { ptr, lib: LibDrm::new().unwrap() };
+
+        Some(unsafe { wrapper.ptr.read() })
+    }
+
+    pub fn encoder_type(&self) -> drmModeEncoderType {
+        drmModeEncoderType::from(self.encoder_type)
+    }
+
+    /// CRTC IDs that can be connected to the encoder
+    pub fn possible_crtc_ids(&self, res: &drmModeRes) -> Vec<u32> {
+        super::possible_crtc_ids(self.possible_crtcs, &res.get_crtc_ids())
+    }
+}
+
+impl Drop for WrapperDrmModeEncoderPtr {
+    fn drop(&mut self) {
+        #[cfg(feature = "link_drm")]
+        let func = bindings::drmModeFreeEncoder;
+        #[cfg(feature = "dynamic_loading")]
+        let func = self.lib.libdrm.drmModeFreeEncoder;
+
+	    unsafe { func(self.ptr); }
+    }
+}
+
+use bindings::{
+    DRM_MODE_ENCODER_NONE,
+    DRM_MODE_ENCODER_DAC,
+    DRM_MODE_ENCODER_TMDS,
+    DRM_MODE_ENCODER_LVDS,
+    DRM_MODE_ENCODER_TVDAC,
+    DRM_MODE_ENCODER_VIRTUAL,
+    DRM_MODE_ENCODER_DSI,
+    DRM_MODE_ENCODER_DPMST,
+    DRM_MODE_ENCODER_DPI,
+};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd)]
+#[repr(u32)]
+pub enum drmModeEncoderType {
+    NONE = DRM_MODE_ENCODER_NONE,
+    DAC = DRM_MODE_ENCODER_DAC,
+    TMDS = DRM_MODE_ENCODER_TMDS,
+    LVDS = DRM_MODE_ENCODER_LVDS,
+    TVDAC = DRM_MODE_ENCODER_TVDAC,
+    VIRTUAL = DRM_MODE_ENCODER_VIRTUAL,
+    DSI = DRM_MODE_ENCODER_DSI,
+    DPMST = DRM_MODE_ENCODER_DPMST,
+    DPI = DRM_MODE_ENCODER_DPI,
+}
+
+impl From<u32> for drmModeEncoderType {
+    fn from(value: u32) -> Self {
+        match value {
+            DRM_MODE_ENCODER_DAC => Self::DAC,
+            DRM_MODE_ENCODER_TMDS => Self::TMDS,
+            DRM_MODE_ENCODER_LVDS => Self::LVDS,
+            DRM_MODE_ENCODER_TVDAC => Self::TVDAC,
+            DRM_MODE_ENCODER_VIRTUAL => Self::VIRTUAL,
+            DRM_MODE_ENCODER_DSI => Self::DSI,
+            DRM_MODE_ENCODER_DPMST => Self::DPMST,
+            DRM_MODE_ENCODER_DPI => Self::DPI,
+            _ => Self::NONE,
+        }
+    }
+}
+
+use std::fmt;
+impl fmt::Display for drmModeEncoderType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
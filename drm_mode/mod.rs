@@ -20,7 +20,14 @@ mod mode_info;
 #[allow(unused_imports)]
 pub use mode_info::*;
 
-// TODO: encoder
+mod encoder;
+pub use encoder::*;
+
+mod plane;
+pub use plane::*;
+
+mod atomic;
+pub use atomic::*;
 
 pub(crate) fn c_char_to_string(c: &[core::ffi::c_char]) -> String {
     let c_name: Vec<u8> = c.iter().map(|c| *c as u8).collect();
@@ -32,6 +39,16 @@ pub(crate) fn c_char_to_string(c: &[core::ffi::c_char]) -> String {
     }.to_string()
 }
 
+/// Convert the `possible_crtcs` bitmask to CRTC IDs
+pub(crate) fn possible_crtc_ids(possible_crtcs: u32, crtc_ids: &[u32]) -> Vec<u32> {
+    crtc_ids
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i < 32 && (possible_crtcs >> i) & 0b1 == 1)
+        .map(|(_, id)| *id)
+        .collect()
+}
+
 use crate::bindings;
 use crate::LibDrm;
 
@@ -86,3 +103,19 @@ pub fn set_all_client_caps(fd: i32) {
         let _ = unsafe { bindings::drmSetClientCap(fd, cap as u64, 1) };
     }
 }
+
+#[test]
+fn test_possible_crtc_ids() {
+    let crtc_ids = [0x50, 0x51, 0x52, 0x53];
+
+    assert_eq!(possible_crtc_ids(0b0000, &crtc_ids), Vec::<u32>::new());
+    assert_eq!(possible_crtc_ids(0b0101, &crtc_ids), vec![0x50, 0x52]);
+    assert_eq!(possible_crtc_ids(0b1111, &crtc_ids), crtc_ids);
+    /* bits beyond `crtc_ids.len()` are ignored */
+    assert_eq!(possible_crtc_ids(0b1101_0010, &crtc_ids), vec![0x51]);
+    assert_eq!(possible_crtc_ids(u32::MAX, &crtc_ids[..2]), vec![0x50, 0x51]);
+    assert_eq!(possible_crtc_ids(0b1, &[]), Vec::<u32>::new());
+    /* `possible_crtcs` is 32-bit */
+    let many: Vec<u32> = (0..40).collect();
+    assert_eq!(possible_crtc_ids(1 << 31, &many), vec![31]);
+}
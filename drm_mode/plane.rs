@@ -0,0 +1,264 @@
+use crate::{bindings, drmModeObjectProperties, drmModeRes, LibDrm};
+use core::ptr::addr_of;
+
+pub use bindings::{drmModePlanePtr, drmModePlaneResPtr, drmModeFormatModifierIterator};
+
+#[derive(Clone)]
+pub struct drmModePlaneRes {
+    pub(crate) ptr: drmModePlaneResPtr,
+    pub(crate) lib: LibDrm,
+}
+
+#[derive(Clone)]
+pub struct drmModePlane {
+    pub(crate) ptr: drmModePlanePtr,
+    pub(crate) lib: LibDrm,
+}
+
+/// A pair of the format (FourCC) and the modifier from the `IN_FORMATS` property
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct drmModeFormatModifier {
+    pub format: u32,
+    pub modifier: u64,
+}
+
+impl drmModeFormatModifier {
+    pub fn format_name(&self) -> String {
+        fourcc_to_string(self.format)
+    }
+}
+
+/// e.g. `0x34325258` -> `"XR24"`
+pub fn fourcc_to_string(fourcc: u32) -> String {
+    fourcc.to_le_bytes().iter().map(|c| *c as char).collect()
+}
+
+impl LibDrm {
+    pub fn get_drm_mode_plane_resources(&self, fd: i32) -> Option<drmModePlaneRes> {
+        #[cfg(feature = "link_drm")]
+        let func = bindings::drmModeGetPlaneResources;
+        #[cfg(feature = "dynamic_loading")]
+        let func = self.libdrm.drmModeGetPlaneResources;
+
+        let ptr = unsafe { func(fd) };
+
+        if ptr.is_null() { return None; }
+
+        Some(drmModePlaneRes { ptr, lib: self.clone() })
+    }
+
+    pub fn get_drm_mode_plane(&self, fd: i32, plane_id: u32) -> Option<drmModePlane> {
+        #[cfg(feature = "link_drm")]
+        let func = bindings::drmModeGetPlane;
+        #[cfg(feature = "dynamic_loading")]
+        let func = self.libdrm.drmModeGetPlane;
+
+        let ptr = unsafe { func(fd, plane_id) };
+
+        if ptr.is_null() { return None; }
+
+        Some(drmModePlane { ptr, lib: self.clone() })
+    }
+
+    pub fn get_drm_mode_plane_props(
+        &self,
+        fd: i32,
+        plane_id: u32,
+    ) -> Option<drmModeObjectProperties> {
+        self.get_drm_mode_object_properties(
+            fd,
+            plane_id,
+            bindings::DRM_MODE_OBJECT_PLANE,
+        )
+    }
+}
+
+impl drmModePlaneRes {
+    #[cfg(feature = "link_drm")]
+    pub fn get(fd: i32) -> Option<Self> {
+        let ptr = unsafe { bindings::drmModeGetPlaneResources(fd) };
+
+        if ptr.is_null() { return None; }
+
+        Some(Self { ptr, lib: LibDrm::new().unwrap() })
+    }
+
+    pub fn plane_ids(&self) -> Vec<u32> {
+        let ptr = unsafe { addr_of!((*self.ptr).planes).read() };
+        let count = unsafe { addr_of!((*self.ptr).count_planes).read() as usize };
+
+        if ptr.is_null() {
+            Vec::new()
+        } else {
+            unsafe { std::slice::from_raw_parts(ptr, count) }.to_vec()
+        }
+    }
+
+    pub fn get_drm_mode_all_planes(&self, fd: i32) -> Vec<drmModePlane> {
+        self.plane_ids().iter().filter_map(|plane_id| {
+            self.lib.get_drm_mode_plane(fd, *plane_id)
+        }).collect()
+    }
+}
+
+impl drmModePlane {
+    #[cfg(feature = "link_drm")]
+    pub fn get(fd: i32, plane_id: u32) -> Option<Self> {
+        let ptr = unsafe { bindings::drmModeGetPlane(fd, plane_id) };
+
+        if ptr.is_null() { return None; }
+
+        Some(Self { ptr, lib: LibDrm::new().unwrap() })
+    }
+
+    pub fn plane_id(&self) -> u32 {
+        unsafe { addr_of!((*self.ptr).plane_id).read() }
+    }
+
+    pub fn crtc_id(&self) -> u32 {
+        unsafe { addr_of!((*self.ptr).crtc_id).read() }
+    }
+
+    pub fn fb_id(&self) -> u32 {
+        unsafe { addr_of!((*self.ptr).fb_id).read() }
+    }
+
+    pub fn crtc_x(&self) -> u32 {
+        unsafe { addr_of!((*self.ptr).crtc_x).read() }
+    }
+
+    pub fn crtc_y(&self) -> u32 {
+        unsafe { addr_of!((*self.ptr).crtc_y).read() }
+    }
+
+    pub fn x(&self) -> u32 {
+        unsafe { addr_of!((*self.ptr).x).read() }
+    }
+
+    pub fn y(&self) -> u32 {
+        unsafe { addr_of!((*self.ptr).y).read() }
+    }
+
+    pub fn gamma_size(&self) -> u32 {
+        unsafe { addr_of!((*self.ptr).gamma_size).read() }
+    }
+
+    /// Bitmask of the CRTC indices in [drmModeRes]
+    pub fn possible_crtcs(&self) -> u32 {
+        unsafe { addr_of!((*self.ptr).possible_crtcs).read() }
+    }
+
+    /// CRTC IDs that can be connected to the plane
+    pub fn possible_crtc_ids(&self, res: &drmModeRes) -> Vec<u32> {
+        super::possible_crtc_ids(self.possible_crtcs(), &res.get_crtc_ids())
+    }
+
+    /// Formats (FourCC) supported by the plane, without modifiers
+    pub fn formats(&self) -> Vec<u32> {
+        let ptr = unsafe { addr_of!((*self.ptr).formats).read() };
+        let count = unsafe { addr_of!((*self.ptr).count_formats).read() as usize };
+
+        if ptr.is_null() {
+            Vec::new()
+        } else {
+            unsafe { std::slice::from_raw_parts(ptr, count) }.to_vec()
+        }
+    }
+
+    pub fn get_drm_mode_plane_properties(&self, fd: i32) -> Option<drmModeObjectProperties> {
+        self.lib.get_drm_mode_plane_props(fd, self.plane_id())
+    }
+
+    /// From the `type` property
+    pub fn plane_type(&self, fd: i32) -> Option<drmModePlaneType> {
+        let props = self.get_drm_mode_plane_properties(fd)?;
+        let (_, value) = props.get_mode_property(fd).into_iter().find(|(prop, _)| prop.name() == "type")?;
+
+        Some(drmModePlaneType::from(value as u32))
+    }
+
+    /// Supported pairs of the format and the modifier, from the `IN_FORMATS` property.
+    /// Returns `None` if the driver does not support modifiers.
+    pub fn get_in_formats(&self, fd: i32) -> Option<Vec<drmModeFormatModifier>> {
+        #[cfg(feature = "link_drm")]
+        let func = bindings::drmModeFormatModifierBlobIterNext;
+        #[cfg(feature = "dynamic_loading")]
+        let func = self.lib.libdrm.drmModeFormatModifierBlobIterNext;
+
+        let props = self.get_drm_mode_plane_properties(fd)?;
+        let (_, blob_id) = props.get_mode_property(fd).into_iter().find(|(prop, _)| prop.name() == "IN_FORMATS")?;
+        let blob = self.lib.get_drm_mode_property_blob(fd, blob_id as u32)?;
+        let mut iter: drmModeFormatModifierIterator = unsafe { std::mem::zeroed() };
+        let mut in_formats = Vec::new();
+
+        while unsafe { func(blob.ptr, &mut iter) } {
+            in_formats.push(drmModeFormatModifier { format: iter.fmt, modifier: iter.mod_ });
+        }
+
+        Some(in_formats)
+    }
+}
+
+impl Drop for drmModePlaneRes {
+    fn drop(&mut self) {
+        #[cfg(feature = "link_drm")]
+        let func = bindings::drmModeFreePlaneResources;
+        #[cfg(feature = "dynamic_loading")]
+        let func = self.lib.libdrm.drmModeFreePlaneResources;
+
+	    unsafe { func(self.ptr); }
+    }
+}
+
+impl Drop for drmModePlane {
+    fn drop(&mut self) {
+        #[cfg(feature = "link_drm")]
+        let func = bindings::drmModeFreePlane;
+        #[cfg(feature = "dynamic_loading")]
+        let func = self.lib.libdrm.drmModeFreePlane;
+
+	    unsafe { func(self.ptr); }
+    }
+}
+
+use bindings::{
+    DRM_PLANE_TYPE_OVERLAY,
+    DRM_PLANE_TYPE_PRIMARY,
+    DRM_PLANE_TYPE_CURSOR,
+};
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd)]
+pub enum drmModePlaneType {
+    OVERLAY,
+    PRIMARY,
+    CURSOR,
+    Unknown(u32),
+}
+
+impl From<u32> for drmModePlaneType {
+    fn from(value: u32) -> Self {
+        match value {
+            DRM_PLANE_TYPE_OVERLAY => Self::OVERLAY,
+            DRM_PLANE_TYPE_PRIMARY => Self::PRIMARY,
+            DRM_PLANE_TYPE_CURSOR => Self::CURSOR,
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+use std::fmt;
+impl fmt::Display for drmModePlaneType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[test]
+fn test_fourcc_to_string() {
+    assert_eq!(fourcc_to_string(0x34325258), "XR24"); // DRM_FORMAT_XRGB8888
+    assert_eq!(fourcc_to_string(0x34324241), "AB24"); // DRM_FORMAT_ABGR8888
+    assert_eq!(fourcc_to_string(0x3231564E), "NV12"); // DRM_FORMAT_NV12
+
+    let fmt = drmModeFormatModifier { format: 0x34325258, modifier: 0 };
+    assert_eq!(fmt.format_name(), "XR24");
+}
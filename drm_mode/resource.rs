@@ -1,4 +1,4 @@
-use crate::{bindings, drmModeConnector, drmModeCrtc, LibDrm};
+use crate::{bindings, drmModeConnector, drmModeCrtc, drmModeEncoder, LibDrm};
 use core::ptr::addr_of;
 
 pub use bindings::{drmModeResPtr, drmModeObjectPropertiesPtr, drmModePropertyPtr};
@@ -28,6 +28,36 @@ impl LibDrm {
 }
 
 impl drmModeRes {
+    pub fn get_crtc_ids(&self) -> Vec<u32> {
+        let ptr = unsafe { addr_of!((*self.ptr).crtcs).read() };
+
+        if ptr.is_null() {
+            return Vec::new();
+        }
+
+        let count = unsafe { addr_of!((*self.ptr).count_crtcs).read() as usize };
+
+        unsafe { std::slice::from_raw_parts(ptr, count) }.to_vec()
+    }
+
+    pub fn get_encoder_ids(&self) -> Vec<u32> {
+        let ptr = unsafe { addr_of!((*self.ptr).encoders).read() };
+
+        if ptr.is_null() {
+            return Vec::new();
+        }
+
+        let count = unsafe { addr_of!((*self.ptr).count_encoders).read() as usize };
+
+        unsafe { std::slice::from_raw_parts(ptr, count) }.to_vec()
+    }
+
+    pub fn get_drm_mode_all_encoders(&self, fd: i32) -> Vec<drmModeEncoder> {
+        self.get_encoder_ids().iter().filter_map(|encoder_id| {
+            self.lib.get_drm_mode_encoder(fd, *encoder_id)
+        }).collect()
+    }
+
     pub fn get_drm_mode_all_connector_current(&self, fd: i32) -> Vec<drmModeConnector> {
         let ptr = unsafe { addr_of!((*self.ptr).connectors).read() };
 
@@ -128,6 +158,12 @@ impl drmModeRes {
             drmModeCrtc::get(fd, *crtc_id)
         }).collect()
     }
+
+    pub fn get_all_encoders(&self, fd: i32) -> Vec<drmModeEncoder> {
+        self.get_encoder_ids().iter().filter_map(|encoder_id| {
+            drmModeEncoder::get(fd, *encoder_id)
+        }).collect()
+    }
 }
 
 impl Drop for drmModeRes {
@@ -1,4 +1,5 @@
-use libdrm_amdgpu_sys::{LibDrm, drmModePropType};
+use libdrm_amdgpu_sys::{LibDrm, drmModePropType, fourcc_to_string};
+use libdrm_amdgpu_sys::DRM_MODE_OBJECT_PLANE;
 use std::fs::File;
 use std::os::fd::AsRawFd;
 
@@ -77,4 +78,55 @@ fn main() {
         }
         println!();
     }
+    for encoder in drm_mode_res.get_drm_mode_all_encoders(fd) {
+        println!(
+            "Encoder {} ({}), crtc: {}, possible_crtcs: {:?}",
+            encoder.encoder_id,
+            encoder.encoder_type(),
+            encoder.crtc_id,
+            encoder.possible_crtc_ids(&drm_mode_res),
+        );
+    }
+    println!();
+
+    let Some(plane_res) = libdrm.get_drm_mode_plane_resources(fd) else { return };
+    let planes = plane_res.get_drm_mode_all_planes(fd);
+
+    for plane in planes.iter() {
+        println!(
+            "Plane {} ({:?}), crtc: {}, fb: {}, possible_crtcs: {:?}",
+            plane.plane_id(),
+            plane.plane_type(fd),
+            plane.crtc_id(),
+            plane.fb_id(),
+            plane.possible_crtc_ids(&drm_mode_res),
+        );
+
+        if let Some(in_formats) = plane.get_in_formats(fd) {
+            println!("    IN_FORMATS");
+            for f in in_formats {
+                println!("        {} {:#018X}", f.format_name(), f.modifier);
+            }
+        } else {
+            let formats: Vec<String> = plane.formats().into_iter().map(fourcc_to_string).collect();
+            println!("    formats: {formats:?}");
+        }
+    }
+    println!();
+
+    /* check the current plane state without applying it */
+    let mut req = libdrm.atomic_request().unwrap();
+    req.test_only(true);
+
+    for plane in planes.iter().filter(|plane| plane.fb_id() != 0) {
+        let r = req
+            .add_property(fd, plane.plane_id(), DRM_MODE_OBJECT_PLANE, "FB_ID", plane.fb_id() as u64)
+            .and_then(|req| req.add_property(fd, plane.plane_id(), DRM_MODE_OBJECT_PLANE, "CRTC_ID", plane.crtc_id() as u64));
+
+        if let Err(e) = r {
+            println!("{e}");
+        }
+    }
+
+    println!("Atomic TEST_ONLY commit ({} properties): {:?}", req.len(), req.commit(fd));
 }
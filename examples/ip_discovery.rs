@@ -1,4 +1,4 @@
-use libdrm_amdgpu_sys::AMDGPU::IpDieEntry;
+use libdrm_amdgpu_sys::AMDGPU::{GcRegister, IpDieEntry, IpVersionCaps};
 use libdrm_amdgpu_sys::LibDrmAmdgpu;
 use std::fs::File;
 
@@ -10,7 +10,13 @@ fn main() {
 
     let path = amdgpu_dev.get_sysfs_path().unwrap();
 
-    let ip_die_entries = IpDieEntry::get_all_entries_from_sysfs(&path);
+    let mut ip_die_entries = IpDieEntry::get_all_entries_from_sysfs(&path);
+
+    /* older kernels, requires root privileges */
+    if ip_die_entries.is_empty() && let Ok(bin) = amdgpu_dev.get_ip_discovery_binary() {
+        println!("gc_info: {:#?}", bin.gc_info);
+        ip_die_entries = bin.dies;
+    }
 
     for entry in &ip_die_entries {
         println!("\ndie_id: {:>2}", entry.die_id);
@@ -19,4 +25,18 @@ fn main() {
             println!("{ip_hw_id:#X?}");
         }
     }
+
+    if let Some(die) = ip_die_entries.first() {
+        let caps = IpVersionCaps::from_ip_die_entry(die);
+
+        println!("{caps:#?}");
+        println!("chip class: {}", caps.chip_class());
+        println!("gfx target: {:?}", caps.gfx_target_version().map(|v| v.to_string()));
+        println!("VCN IP block: {:?}", caps.vcn_ip_block());
+        println!("SMU IP block: {:?}", caps.smu_ip_block());
+
+        if let Some(offset) = die.gc_register_offset(GcRegister::GRBM_STATUS, 0) {
+            println!("GRBM_STATUS ({offset:#X}): {:#010X?}", amdgpu_dev.read_mm_registers(offset));
+        }
+    }
 }
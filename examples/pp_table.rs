@@ -20,6 +20,14 @@ fn main() {
         use AMDGPU::VBIOS::VbiosParser;
 
         let vbios_parser = VbiosParser::new(vbios_image);
+
+        if vbios_parser.is_atomfirmware() == Some(false) {
+            let rom_header = vbios_parser.get_legacy_rom_header()?;
+            let data_table = vbios_parser.get_legacy_data_table(&rom_header)?;
+
+            return Some(vbios_parser.get_legacy_powerplay_table_bytes(&data_table)?.to_vec());
+        }
+
         let rom_header = vbios_parser.get_atom_rom_header()?;
         let data_table = vbios_parser.get_atom_data_table(&rom_header)?;
 
@@ -38,5 +46,13 @@ fn main() {
         };
 
         println!("from {src}: {pp_table:#?}");
+
+        if let Some(dpm) = AMDGPU::pp_table::LegacyDpmTables::decode(&bytes) {
+            println!("DPM tables from {src}: {dpm:#?}");
+        }
+
+        if let Some(summary) = AMDGPU::pp_table::PPTableSummary::decode(&bytes) {
+            println!("summary from {src}: {summary:#?}");
+        }
     }
 }
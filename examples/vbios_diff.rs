@@ -0,0 +1,22 @@
+use libdrm_amdgpu_sys::*;
+use AMDGPU::VBIOS::VbiosParser;
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    let [_, old, new] = &args[..] else {
+        println!("usage: vbios_diff <old VBIOS image> <new VBIOS image>");
+        return;
+    };
+    let [old, new] = [old, new].map(|path| VbiosParser::new(std::fs::read(path).unwrap()));
+
+    let Some(diffs) = old.diff_data_tables(&new) else {
+        println!("Not atomfirmware VBIOS images");
+        return;
+    };
+
+    for diff in &diffs {
+        println!("{diff}");
+    }
+
+    println!("{} fields differ", diffs.len());
+}
@@ -33,6 +33,12 @@ fn main() {
         if args.contains(&"-d".to_string()) || args.contains(&"--dump".to_string()) {
             // if let Ok(vbios_image) = unsafe { amdgpu_dev.get_vbios_image_with_size(vbios.size) } {
             if let Ok(vbios_image) = amdgpu_dev.get_vbios_image() {
+                let parser = AMDGPU::VBIOS::VbiosParser::new(vbios_image.clone());
+
+                if !parser.verify_checksum() {
+                    println!("\nWarning: VBIOS checksum mismatch");
+                }
+
                 let name = vbios.name.replace(' ', "_").replace('/', "_");
                 dump(&vbios_image, name).unwrap();
             }
@@ -20,6 +20,28 @@ fn main() {
         println!("name: {name:?}");
     }
 
+    for image in vbios_parser.pci_rom_images() {
+        println!("{image}");
+    }
+
+    if vbios_parser.is_atomfirmware() == Some(false) {
+        let rom_header = vbios_parser.get_legacy_rom_header().unwrap();
+        println!("{rom_header:#X?}");
+
+        let data_table = vbios_parser.get_legacy_data_table(&rom_header).unwrap();
+        println!("{data_table:#X?}");
+
+        if let Some(firmware_info) = vbios_parser.get_legacy_firmware_info(&data_table) {
+            println!("firmwareinfo: {firmware_info:#?}");
+        }
+
+        if let Some(pp_table) = vbios_parser.get_legacy_powerplay_table(&data_table) {
+            println!("powerplay: {pp_table:#?}");
+        }
+
+        return;
+    }
+
     let rom_header = vbios_parser.get_atom_rom_header().unwrap();
     println!("{rom_header:#X?}");
 
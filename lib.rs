@@ -24,16 +24,40 @@ mod bindings {
     #[cfg(feature = "dynamic_loading")]
     pub use dyn_drm_amdgpu::*;
 
+    /* drm_mode.h is included in both, disambiguate the glob imports */
+    #[cfg(feature = "dynamic_loading")]
+    pub use dyn_drm::{
+        DRM_MODE_ENCODER_NONE,
+        DRM_MODE_ENCODER_DAC,
+        DRM_MODE_ENCODER_TMDS,
+        DRM_MODE_ENCODER_LVDS,
+        DRM_MODE_ENCODER_TVDAC,
+        DRM_MODE_ENCODER_VIRTUAL,
+        DRM_MODE_ENCODER_DSI,
+        DRM_MODE_ENCODER_DPMST,
+        DRM_MODE_ENCODER_DPI,
+        DRM_MODE_ATOMIC_TEST_ONLY,
+        DRM_MODE_ATOMIC_NONBLOCK,
+        DRM_MODE_ATOMIC_ALLOW_MODESET,
+    };
+
     mod amdgpu_ids;
     pub use amdgpu_ids::AMDGPU_IDS;
     mod amdgpu_ids_2;
     pub use amdgpu_ids_2::AMDGPU_IDS_2;
 
+    pub mod atombios;
+    pub mod discovery;
+
     pub mod ppt {
         pub mod smu_v11_0_0_ppt;
         pub mod smu_v11_0_7_ppt;
         pub mod smu_v13_0_0_ppt;
         pub mod smu_v13_0_7_ppt;
+        pub mod tonga_ppt;
+        pub mod vega10_ppt;
+        pub mod vega20_ppt;
+        pub mod smu_v14_0_2_ppt;
     }
 }
 
@@ -0,0 +1,122 @@
+use std::path::PathBuf;
+use super::BUS_INFO;
+
+/* ref: drivers/pci/pcie/aer.c */
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum AerType {
+    Correctable,
+    NonFatal,
+    Fatal,
+}
+
+impl AerType {
+    pub const fn sysfs_name(&self) -> &str {
+        match self {
+            Self::Correctable => "aer_dev_correctable",
+            Self::NonFatal => "aer_dev_nonfatal",
+            Self::Fatal => "aer_dev_fatal",
+        }
+    }
+
+    const fn total_name(&self) -> &str {
+        match self {
+            Self::Correctable => "TOTAL_ERR_COR",
+            Self::NonFatal => "TOTAL_ERR_NONFATAL",
+            Self::Fatal => "TOTAL_ERR_FATAL",
+        }
+    }
+}
+
+/// PCIe Advanced Error Reporting counters (`aer_dev_*`)
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AerCounters {
+    pub type_: AerType,
+    /// e.g. `("RxErr", 0)`, `("BadTLP", 0)`, `("MalfTLP", 0)`
+    pub counters: Vec<(String, u64)>,
+    pub total: u64,
+}
+
+impl AerCounters {
+    pub fn get_from_sysfs<P: Into<PathBuf>>(sysfs_path: P, type_: AerType) -> Option<Self> {
+        let s = std::fs::read_to_string(sysfs_path.into().join(type_.sysfs_name())).ok()?;
+
+        Self::parse(&s, type_)
+    }
+
+    /// `None` if neither a counter nor the total is found
+    pub fn parse(s: &str, type_: AerType) -> Option<Self> {
+        let mut counters = Vec::with_capacity(32);
+        let mut total: Option<u64> = None;
+
+        for line in s.lines() {
+            let Some((name, val)) = line.split_once(' ') else { continue };
+            let Ok(val) = val.trim().parse::<u64>() else { continue };
+
+            if name == type_.total_name() {
+                total = Some(val);
+            } else {
+                counters.push((name.to_string(), val));
+            }
+        }
+
+        if counters.is_empty() && total.is_none() { return None }
+
+        let total = total.unwrap_or_else(|| counters.iter().map(|(_, v)| v).sum());
+
+        Some(Self { type_, counters, total })
+    }
+
+    pub fn get(&self, name: &str) -> Option<u64> {
+        self.counters.iter().find(|(n, _)| n == name).map(|(_, v)| *v)
+    }
+
+    /// Returns only non-zero counters
+    pub fn non_zero_counters(&self) -> Vec<(&str, u64)> {
+        self.counters
+            .iter()
+            .filter(|(_, v)| *v != 0)
+            .map(|(n, v)| (n.as_str(), *v))
+            .collect()
+    }
+}
+
+#[test]
+fn test_aer_counters_parse() {
+    let s = "RxErr 2\nBadTLP 0\nBadDLLP 5\nTOTAL_ERR_COR 7\n";
+    let aer = AerCounters::parse(s, AerType::Correctable).unwrap();
+
+    assert_eq!(aer.total, 7);
+    assert_eq!(aer.get("BadDLLP"), Some(5));
+    assert_eq!(aer.non_zero_counters(), vec![("RxErr", 2), ("BadDLLP", 5)]);
+
+    let aer = AerCounters::parse("TOTAL_ERR_FATAL 3\n", AerType::Fatal).unwrap();
+
+    assert_eq!(aer.total, 3);
+    assert!(aer.counters.is_empty());
+    assert_eq!(AerCounters::parse("", AerType::Correctable), None);
+    assert_eq!(AerCounters::parse("not a counter\n", AerType::NonFatal), None);
+}
+
+impl BUS_INFO {
+    /// Get AER counters (`aer_dev_correctable`, `aer_dev_nonfatal`, `aer_dev_fatal`) from sysfs.  
+    /// Also available for the upstream ports,
+    /// [BUS_INFO::get_gpu_pcie_port_bus], [BUS_INFO::get_system_pcie_port_bus].
+    pub fn get_aer_counters(&self, type_: AerType) -> Option<AerCounters> {
+        AerCounters::get_from_sysfs(self.get_sysfs_path(), type_)
+    }
+
+    /// Get all AER counters in the order: correctable, non-fatal, fatal
+    pub fn get_all_aer_counters(&self) -> [Option<AerCounters>; 3] {
+        [AerType::Correctable, AerType::NonFatal, AerType::Fatal]
+            .map(|type_| self.get_aer_counters(type_))
+    }
+
+    /// Get the number of PCIe replays (NAKs) from sysfs (`pcie_replay_count`)  
+    /// Only for the AMDGPU device.
+    pub fn get_pcie_replay_count(&self) -> Option<u64> {
+        let s = std::fs::read_to_string(self.get_sysfs_path().join("pcie_replay_count")).ok()?;
+
+        s.trim_end().parse().ok()
+    }
+}
@@ -45,6 +45,21 @@ impl LINK {
         Some(r#gen)
     }
 
+    /// Approximate bandwidth per direction (MB/s), excluding the encoding overhead
+    pub fn bandwidth_mb_per_sec(&self) -> Option<u32> {
+        let per_lane = match self.r#gen {
+            1 => 250, // 8b/10b
+            2 => 500, // 8b/10b
+            3 => 985, // 128b/130b
+            4 => 1969, // 128b/130b
+            5 => 3938, // 128b/130b
+            6 => 7563, // FLIT
+            _ => return None,
+        };
+
+        Some(per_lane * self.width as u32)
+    }
+
     fn parse_dpm_line(s: &str) -> Option<Self> {
         let mut r#gen: Option<u8> = None;
         let mut width: Option<u8> = None;
@@ -6,3 +6,12 @@ pub use status::*;
 
 mod link;
 pub use link::*;
+
+mod aer;
+pub use aer::*;
+
+mod pcie_bw;
+pub use pcie_bw::*;
+
+mod topology;
+pub use topology::*;
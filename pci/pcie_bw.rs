@@ -0,0 +1,61 @@
+use std::path::PathBuf;
+use super::BUS_INFO;
+
+const PCIE_BW: &str = "pcie_bw";
+
+/// PCIe bandwidth usage from sysfs (`pcie_bw`).  
+/// The driver counts the packets over 1 second, so reading it takes about 1 second.  
+/// ref: <https://www.kernel.org/doc/html/latest/gpu/amdgpu/driver-misc.html#pcie-bw>
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct PcieBw {
+    pub received_packets: u64,
+    pub sent_packets: u64,
+    /// Maximum payload size (bytes)
+    pub max_payload_size: i32,
+}
+
+impl PcieBw {
+    pub fn get_from_sysfs<P: Into<PathBuf>>(sysfs_path: P) -> Option<Self> {
+        let s = std::fs::read_to_string(sysfs_path.into().join(PCIE_BW)).ok()?;
+
+        Self::parse(&s)
+    }
+
+    /// "%llu %llu %i"
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut split = s.split_whitespace();
+        let [received, sent, mps] = [split.next()?, split.next()?, split.next()?];
+
+        Some(Self {
+            received_packets: received.parse().ok()?,
+            sent_packets: sent.parse().ok()?,
+            max_payload_size: mps.parse().ok()?,
+        })
+    }
+
+    /// Bytes/sec, assuming all packets are the maximum payload size
+    pub fn received_bytes_per_sec(&self) -> u64 {
+        self.received_packets.saturating_mul(self.max_payload_size.max(0) as u64)
+    }
+
+    /// Bytes/sec, assuming all packets are the maximum payload size
+    pub fn sent_bytes_per_sec(&self) -> u64 {
+        self.sent_packets.saturating_mul(self.max_payload_size.max(0) as u64)
+    }
+}
+
+#[test]
+fn test_pcie_bw_parse() {
+    let bw = PcieBw::parse("1024 512 256\n").unwrap();
+
+    assert_eq!(bw.received_bytes_per_sec(), 1024 * 256);
+    assert_eq!(bw.sent_bytes_per_sec(), 512 * 256);
+}
+
+impl BUS_INFO {
+    /// Get PCIe bandwidth usage from sysfs (`pcie_bw`)  
+    /// Only for the AMDGPU device, and it takes about 1 second.
+    pub fn get_pcie_bw(&self) -> Option<PcieBw> {
+        PcieBw::get_from_sysfs(self.get_sysfs_path())
+    }
+}
@@ -0,0 +1,155 @@
+use std::path::{Path, PathBuf};
+use super::{BUS_INFO, LINK, STATUS};
+
+/// ASPM state of the link from sysfs (`link/*_aspm`)
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct PcieAspm {
+    pub l0s: Option<bool>,
+    pub l1: Option<bool>,
+    pub l1_1: Option<bool>,
+    pub l1_2: Option<bool>,
+    pub l1_1_pcipm: Option<bool>,
+    pub l1_2_pcipm: Option<bool>,
+}
+
+impl PcieAspm {
+    pub fn get_from_sysfs<P: Into<PathBuf>>(sysfs_path: P) -> Self {
+        let path = sysfs_path.into().join("link");
+        let [l0s, l1, l1_1, l1_2, l1_1_pcipm, l1_2_pcipm] = [
+            "l0s_aspm",
+            "l1_aspm",
+            "l1_1_aspm",
+            "l1_2_aspm",
+            "l1_1_pcipm",
+            "l1_2_pcipm",
+        ].map(|name| {
+            let s = std::fs::read_to_string(path.join(name)).ok()?;
+
+            Some(s.trim_end() == "1")
+        });
+
+        Self { l0s, l1, l1_1, l1_2, l1_1_pcipm, l1_2_pcipm }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        [self.l0s, self.l1, self.l1_1, self.l1_2, self.l1_1_pcipm, self.l1_2_pcipm]
+            .contains(&Some(true))
+    }
+}
+
+/// A device on the path from the GPU to the root port
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PcieHop {
+    pub bus_info: BUS_INFO,
+    pub vendor_id: Option<u32>,
+    pub device_id: Option<u32>,
+    pub class: Option<u32>,
+    pub current_link: Option<LINK>,
+    pub max_link: Option<LINK>,
+    pub aspm: PcieAspm,
+}
+
+impl PcieHop {
+    pub fn get_from_sysfs<P: AsRef<Path>>(sysfs_path: P) -> Option<Self> {
+        let path = sysfs_path.as_ref();
+        let bus_info: BUS_INFO = path.file_name()?.to_str()?.parse().ok()?;
+        let [vendor_id, device_id, class] = ["vendor", "device", "class"].map(|name| {
+            let s = std::fs::read_to_string(path.join(name)).ok()?;
+
+            u32::from_str_radix(s.trim_start_matches("0x").trim_end(), 16).ok()
+        });
+
+        Some(Self {
+            bus_info,
+            vendor_id,
+            device_id,
+            class,
+            current_link: LINK::get_from_sysfs_with_status(path, STATUS::Current),
+            max_link: LINK::get_from_sysfs_with_status(path, STATUS::Max),
+            aspm: PcieAspm::get_from_sysfs(path),
+        })
+    }
+
+    /// PCI-to-PCI Bridge (root port, switch port)
+    pub fn is_bridge(&self) -> bool {
+        self.class.map(|class| (class >> 8) == 0x0604).unwrap_or(false)
+    }
+
+    /// The link is running below its maximum speed or width.
+    pub fn is_downgraded(&self) -> bool {
+        let [Some(cur), Some(max)] = [self.current_link, self.max_link] else { return false };
+
+        cur.r#gen < max.r#gen || cur.width < max.width
+    }
+}
+
+/// Full chain from the GPU through every switch/bridge up to the root port.
+/// `hops[0]` is the GPU, and the last entry is the root port.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PcieTopology {
+    pub hops: Vec<PcieHop>,
+}
+
+impl PcieTopology {
+    /// `sysfs_path`: `/sys/bus/pci/devices/#/`
+    pub fn get_from_sysfs<P: AsRef<Path>>(sysfs_path: P) -> Option<Self> {
+        let path = sysfs_path.as_ref().canonicalize().ok()?;
+        let hops: Vec<PcieHop> = path
+            .ancestors()
+            .map_while(PcieHop::get_from_sysfs)
+            .collect();
+
+        if hops.is_empty() { return None }
+
+        Some(Self { hops })
+    }
+
+    pub fn gpu(&self) -> Option<&PcieHop> {
+        self.hops.first()
+    }
+
+    pub fn root_port(&self) -> Option<&PcieHop> {
+        self.hops.last()
+    }
+
+    /// Returns the hop with the lowest current link bandwidth.
+    /// If there are multiple, the one closest to the GPU.
+    pub fn bottleneck(&self) -> Option<&PcieHop> {
+        self.hops
+            .iter()
+            .filter_map(|hop| Some((hop, hop.current_link?.bandwidth_mb_per_sec()?)))
+            .reduce(|min, cur| if cur.1 < min.1 { cur } else { min })
+            .map(|(hop, _)| hop)
+    }
+
+    /// Returns the hops running below their maximum link speed or width.
+    pub fn downgraded_hops(&self) -> Vec<&PcieHop> {
+        self.hops.iter().filter(|hop| hop.is_downgraded()).collect()
+    }
+}
+
+#[test]
+fn test_pcie_topology_bottleneck() {
+    let hop = |bus: u8, r#gen: u8, width: u8| PcieHop {
+        bus_info: BUS_INFO { domain: 0, bus, dev: 0, func: 0 },
+        vendor_id: None,
+        device_id: None,
+        class: None,
+        current_link: Some(LINK { r#gen, width }),
+        max_link: Some(LINK { r#gen: 4, width: 16 }),
+        aspm: PcieAspm::default(),
+    };
+    let topology = PcieTopology {
+        hops: vec![hop(3, 4, 16), hop(2, 4, 16), hop(1, 3, 8), hop(0, 4, 16)],
+    };
+
+    assert_eq!(topology.bottleneck().map(|hop| hop.bus_info.bus), Some(1));
+    assert_eq!(topology.downgraded_hops().len(), 1);
+}
+
+impl BUS_INFO {
+    /// Walk from the device up to the root port via sysfs
+    pub fn get_pcie_topology(&self) -> Option<PcieTopology> {
+        PcieTopology::get_from_sysfs(self.get_sysfs_path())
+    }
+}
@@ -0,0 +1,79 @@
+/*
+ * Copyright 2023 Advanced Micro Devices, Inc.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a
+ * copy of this software and associated documentation files (the "Software"),
+ * to deal in the Software without restriction, including without limitation
+ * the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+ * THE COPYRIGHT HOLDER(S) OR AUTHOR(S) BE LIABLE FOR ANY CLAIM, DAMAGES OR
+ * OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE,
+ * ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+ * OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+/* Trimmed to the PowerPlay table, `PPTable_t` (smu14_driver_if_v14_0.h) is not vendored */
+
+#ifndef SMU_14_0_2_PPTABLE_H
+#define SMU_14_0_2_PPTABLE_H
+
+
+#pragma pack(push, 1)
+
+#define SMU_14_0_2_TABLE_FORMAT_REVISION 3
+
+#define SMU_14_0_2_MAX_ODFEATURE 32
+#define SMU_14_0_2_MAX_PMSETTING 32
+#define SMU_14_0_2_MAX_ODSETTING 64
+
+struct smu_14_0_2_overdrive_table {
+	uint8_t revision;
+	uint8_t reserve[3];
+	uint8_t cap[SMU_14_0_2_MAX_ODFEATURE];
+	int32_t max[SMU_14_0_2_MAX_ODSETTING];
+	int32_t min[SMU_14_0_2_MAX_ODSETTING];
+	int16_t pm_setting[SMU_14_0_2_MAX_PMSETTING];
+};
+
+struct smu_14_0_2_powerplay_table {
+	struct atom_common_table_header header;
+	uint8_t table_revision;
+	uint8_t padding;
+	uint16_t pmfw_pptable_start_offset;
+	uint16_t pmfw_pptable_size;
+	uint16_t pmfw_sku_table_start_offset;
+	uint16_t pmfw_sku_table_size;
+	uint16_t pmfw_board_table_start_offset;
+	uint16_t pmfw_board_table_size;
+	uint16_t pmfw_custom_sku_table_start_offset;
+	uint16_t pmfw_custom_sku_table_size;
+	uint32_t golden_pp_id;
+	uint32_t golden_revision;
+	uint16_t format_id;
+	uint32_t platform_caps;
+
+	uint8_t thermal_controller_type;
+
+	uint16_t small_power_limit1;
+	uint16_t small_power_limit2;
+	uint16_t boost_power_limit;
+	uint16_t software_shutdown_temp;
+
+	uint8_t reserve[143];
+
+	struct smu_14_0_2_overdrive_table overdrive_table;
+	uint8_t padding1;
+	PPTable_t smc_pptable;
+};
+
+#pragma pack(pop)
+
+#endif
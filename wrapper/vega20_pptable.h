@@ -0,0 +1,86 @@
+/*
+ * Copyright 2018 Advanced Micro Devices, Inc.
+ *
+ * Permission is hereby granted, free of charge, to any person obtaining a
+ * copy of this software and associated documentation files (the "Software"),
+ * to deal in the Software without restriction, including without limitation
+ * the rights to use, copy, modify, merge, publish, distribute, sublicense,
+ * and/or sell copies of the Software, and to permit persons to whom the
+ * Software is furnished to do so, subject to the following conditions:
+ *
+ * The above copyright notice and this permission notice shall be included in
+ * all copies or substantial portions of the Software.
+ *
+ * THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+ * IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+ * FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT.  IN NO EVENT SHALL
+ * THE COPYRIGHT HOLDER(S) OR AUTHOR(S) BE LIABLE FOR ANY CLAIM, DAMAGES OR
+ * OTHER LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE,
+ * ARISING FROM, OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR
+ * OTHER DEALINGS IN THE SOFTWARE.
+ */
+
+/* Trimmed to the PowerPlay table, `PPTable_t` (smu11_driver_if.h) is not vendored */
+
+#ifndef _VEGA20_PPTABLE_H_
+#define _VEGA20_PPTABLE_H_
+
+#pragma pack(push, 1)
+
+#define ATOM_VEGA20_TABLE_REVISION_VEGA20         11
+
+#define ATOM_VEGA20_ODFEATURE_MAX_COUNT           32
+#define ATOM_VEGA20_ODSETTING_MAX_COUNT           32
+#define ATOM_VEGA20_PPCLOCK_MAX_COUNT             16
+
+typedef struct _ATOM_VEGA20_OVERDRIVE8_RECORD
+{
+  UCHAR ucODTableRevision;
+  ULONG ODFeatureCount;
+  UCHAR ODFeatureCapabilities [ATOM_VEGA20_ODFEATURE_MAX_COUNT];
+  ULONG ODSettingCount;
+  ULONG ODSettingsMax [ATOM_VEGA20_ODSETTING_MAX_COUNT];
+  ULONG ODSettingsMin [ATOM_VEGA20_ODSETTING_MAX_COUNT];
+} ATOM_VEGA20_OVERDRIVE8_RECORD;
+
+typedef struct _ATOM_VEGA20_POWER_SAVING_CLOCK_RECORD
+{
+  UCHAR ucTableRevision;
+  ULONG PowerSavingClockCount;
+  ULONG PowerSavingClockMax  [ATOM_VEGA20_PPCLOCK_MAX_COUNT];
+  ULONG PowerSavingClockMin  [ATOM_VEGA20_PPCLOCK_MAX_COUNT];
+} ATOM_VEGA20_POWER_SAVING_CLOCK_RECORD;
+
+typedef struct _ATOM_VEGA20_POWERPLAYTABLE
+{
+  struct atom_common_table_header sHeader;
+  UCHAR  ucTableRevision;
+  USHORT usTableSize;
+  ULONG  ulGoldenPPID;
+  ULONG  ulGoldenRevision;
+  USHORT usFormatID;
+
+  ULONG  ulPlatformCaps;
+
+  UCHAR  ucThermalControllerType;
+
+  USHORT usSmallPowerLimit1;
+  USHORT usSmallPowerLimit2;
+  USHORT usBoostPowerLimit;
+  USHORT usODTurboPowerLimit;
+  USHORT usODPowerSavePowerLimit;
+  USHORT usSoftwareShutdownTemp;
+
+  ATOM_VEGA20_POWER_SAVING_CLOCK_RECORD PowerSavingClockTable;
+
+  ATOM_VEGA20_OVERDRIVE8_RECORD OverDrive8Table;
+
+  USHORT usReserve[5];
+
+  PPTable_t smcPPTable;
+
+} ATOM_Vega20_POWERPLAYTABLE;
+
+#pragma pack(pop)
+
+#endif
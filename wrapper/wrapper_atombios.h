@@ -0,0 +1,6 @@
+#include "wrapper_atombios_types.h"
+
+/* atombios.h requires it */
+#define ATOM_BIG_ENDIAN 0
+
+#include "atombios.h"
@@ -0,0 +1,7 @@
+/* The types of atom-types.h, for atombios.h and the PowerPlay table headers of hwmgr
+ * (Tonga, Fiji, Polaris, Vega10 and Vega20). */
+#include <stdint.h>
+
+typedef uint16_t USHORT;
+typedef uint32_t ULONG;
+typedef uint8_t UCHAR;
@@ -0,0 +1,8 @@
+/* Placeholder for `PPTable_t` of the SMU driver interface headers that are not vendored.
+ * It is replaced with a zero-sized type in the generated bindings,
+ * only the offset of the `PPTable_t` member is used. */
+#include <stdint.h>
+
+typedef struct {
+  uint8_t _opaque;
+} PPTable_t;